@@ -5,8 +5,7 @@
 //! to the Polyethical Manifold Specification and PAS monitoring.
 //! It likely integrates various components like the Recursive Cognition Engine.
 
-use crate::recursive_cognition_engine::{CognitiveState, Stimulus, EthicalAssessmentReport};
-use crate::mythos_memory_core::HistoricalClaim; // Example dependency
+use crate::recursive_cognition_engine::{CognitiveState, Stimulus};
 
 /// Represents the central governing intelligence of the Or4cl3 system.
 pub trait AegisCore {
@@ -33,6 +33,12 @@ impl AstraeaCoordinator {
     }
 }
 
+impl Default for AstraeaCoordinator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl CognitiveMeshCoordinator for AstraeaCoordinator {
     fn register_agent(&mut self, agent_id: String, capabilities: Vec<String>) -> Result<(), String> {
         if self.agents.contains_key(&agent_id) {
@@ -0,0 +1,25 @@
+// or4cl3_core/src/bin/or4cl3.rs
+
+//! Interactive terminal chat with OR4CL3: `cargo run --bin or4cl3`.
+//! Type `/help` in the session for the available commands.
+
+use std::io;
+use std::sync::{Arc, RwLock};
+
+use or4cl3_core::conversational_interface::repl::Repl;
+use or4cl3_core::polyethical_manifold::{EthicalRulebook, RulebookEthicalAssessor};
+use or4cl3_core::recursive_cognition_engine::learning::{EngineParameters, FeedbackStore};
+use or4cl3_core::recursive_cognition_engine::BasicRecursiveCognitionEngine;
+
+fn main() -> io::Result<()> {
+    // Feedback given with /feedback is learned from for the rest of the session
+    let feedback_store = Arc::new(FeedbackStore::new(Arc::new(RwLock::new(EngineParameters::default()))));
+    let assessor = RulebookEthicalAssessor::new(EthicalRulebook::builtin()).with_parameters(feedback_store.parameters());
+    let engine = BasicRecursiveCognitionEngine::new()
+        .with_ethical_assessor(assessor)
+        .with_feedback_store(feedback_store);
+    let user_id = std::env::var("USER").unwrap_or_else(|_| "local_user".to_string());
+    let mut repl = Repl::new(engine, &user_id);
+    repl.run(io::stdin().lock(), &mut io::stdout().lock())
+}
+
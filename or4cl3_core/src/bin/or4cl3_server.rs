@@ -0,0 +1,48 @@
+// or4cl3_core/src/bin/or4cl3_server.rs
+
+//! Local HTTP/JSON server for the OR4CL3 conversational interface.
+//! Build with `cargo run --features server --bin or4cl3-server -- [--addr 127.0.0.1:8080]`.
+//! See `conversational_interface::http` for the endpoints.
+
+use std::net::TcpListener;
+use std::process::ExitCode;
+
+use or4cl3_core::conversational_interface::http::HttpServer;
+use or4cl3_core::conversational_interface::BasicConversationalInterface;
+use or4cl3_core::polyethical_manifold::{EthicalRulebook, RulebookEthicalAssessor};
+use or4cl3_core::recursive_cognition_engine::BasicRecursiveCognitionEngine;
+
+const DEFAULT_ADDR: &str = "127.0.0.1:8080";
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let mut addr = DEFAULT_ADDR.to_string();
+    while let Some(arg) = args.next() {
+        match (arg.as_str(), args.next()) {
+            ("--addr", Some(value)) => addr = value,
+            _ => {
+                eprintln!("Usage: or4cl3-server [--addr HOST:PORT]  (default {})", DEFAULT_ADDR);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    let listener = match TcpListener::bind(&addr) {
+        Ok(listener) => listener,
+        Err(e) => {
+            eprintln!("Failed to bind {}: {}", addr, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let engine = BasicRecursiveCognitionEngine::new()
+        .with_ethical_assessor(RulebookEthicalAssessor::new(EthicalRulebook::builtin()));
+    let server = HttpServer::new(BasicConversationalInterface::new(engine));
+    eprintln!("OR4CL3 listening on http://{}", addr);
+    match server.serve(listener) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Server stopped: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
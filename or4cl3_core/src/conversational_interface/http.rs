@@ -0,0 +1,509 @@
+// or4cl3_core/src/conversational_interface/http.rs
+
+//! Minimal HTTP/1.1 JSON front end for the conversational interface, built on
+//! `std::net` so it runs locally without external services. Each connection is
+//! served on its own thread, up to `max_connections` at once, and closed after one response.
+//!
+//! | Method | Path            | Body        | Response                                  |
+//! |--------|-----------------|-------------|-------------------------------------------|
+//! | GET    | `/health`       | -           | `{"status": "ok", "version": ...}`        |
+//! | GET    | `/schema`       | -           | JSON Schemas of `UserQuery`, `SystemResponse` |
+//! | POST   | `/query`        | `UserQuery` | `SystemResponse`                          |
+//! | POST   | `/query/stream` | `UserQuery` | server-sent `ResponseEvent`s              |
+//!
+//! `/query` answers with another format when `Accept` names a renderer's media type
+//! (`text/markdown`, `text/plain`, `application/ssml+xml` or
+//! `application/vnd.or4cl3.response+json`; see `render`).
+//! Errors are returned as `{"error": {"message", "stage", "retryable"}}`. Request heads over
+//! `MAX_HEADER_BYTES` or `MAX_HEADERS` headers are refused with 431, and connections beyond
+//! `max_connections` with 503.
+
+use std::collections::HashMap;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::recursive_cognition_engine::{CognitionError, CognitionErrorKind};
+
+use super::render::renderer_for_media_type;
+use super::streaming::{ResponseEvent, StreamingConversationalInterface};
+use super::{Or4cl3ConversationalInterface, UserQuery};
+
+/// Request bodies larger than this are refused with 413.
+pub const MAX_BODY_BYTES: usize = 1 << 20;
+/// Request line plus headers larger than this are refused with 431.
+pub const MAX_HEADER_BYTES: usize = 16 << 10;
+/// Requests with more headers than this are refused with 431.
+pub const MAX_HEADERS: usize = 100;
+/// Connections served at once by default; see `HttpServer::with_max_connections`.
+pub const DEFAULT_MAX_CONNECTIONS: usize = 64;
+const READ_TIMEOUT: Duration = Duration::from_secs(30);
+const WRITE_TIMEOUT: Duration = Duration::from_secs(30); // Bounds how long a client that stops reading holds its slot
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpRequest {
+    pub method: String,
+    pub path: String, // Without the query string
+    pub headers: HashMap<String, String>, // Lowercased names
+    pub body: Vec<u8>,
+}
+
+/// A request that could not be served, with the status to answer it with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpError {
+    pub status: u16,
+    pub message: String,
+}
+
+impl HttpError {
+    fn new(status: u16, message: impl Into<String>) -> Self {
+        Self { status, message: message.into() }
+    }
+
+    /// Names what was being read in a 400 read failure; other errors are returned unchanged.
+    fn context(self, part: &str) -> Self {
+        match self.status {
+            400 => Self::new(400, format!("Failed to read {}: {}", part, self.message)),
+            _ => self,
+        }
+    }
+}
+
+/// Reads one request: request line, headers, and a `Content-Length` body.
+pub fn read_request(reader: &mut impl BufRead) -> Result<HttpRequest, HttpError> {
+    let mut head = Read::take(&mut *reader, MAX_HEADER_BYTES as u64);
+    let line = read_head_line(&mut head).map_err(|e| e.context("request"))?;
+    let mut parts = line.split_whitespace();
+    let (method, target) = match (parts.next(), parts.next()) {
+        (Some(method), Some(target)) => (method.to_string(), target),
+        _ => return Err(HttpError::new(400, "Malformed request line")),
+    };
+    let path = target.split('?').next().unwrap_or_default().to_string();
+
+    let mut headers = HashMap::new();
+    let mut header_count = 0;
+    loop {
+        let header = read_head_line(&mut head).map_err(|e| e.context("headers"))?;
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        header_count += 1;
+        if header_count > MAX_HEADERS {
+            return Err(HttpError::new(431, format!("Request has more than {} headers", MAX_HEADERS)));
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            headers.insert(name.trim().to_ascii_lowercase(), value.trim().to_string());
+        }
+    }
+
+    let length = match headers.get("content-length") {
+        Some(value) => value.parse::<usize>().map_err(|_| HttpError::new(400, "Invalid Content-Length"))?,
+        None => 0,
+    };
+    if length > MAX_BODY_BYTES {
+        return Err(HttpError::new(413, format!("Request body exceeds {} bytes", MAX_BODY_BYTES)));
+    }
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).map_err(|e| HttpError::new(400, format!("Failed to read body: {}", e)))?;
+    Ok(HttpRequest { method, path, headers, body })
+}
+
+/// Reads one line of the request head, which `head` limits to `MAX_HEADER_BYTES` in total.
+fn read_head_line(head: &mut io::Take<impl BufRead>) -> Result<String, HttpError> {
+    let mut line = String::new();
+    head.read_line(&mut line).map_err(|e| HttpError::new(400, e.to_string()))?;
+    if !line.ends_with('\n') && head.limit() == 0 {
+        return Err(HttpError::new(431, format!("Request head exceeds {} bytes", MAX_HEADER_BYTES)));
+    }
+    Ok(line)
+}
+
+/// Serves any conversational interface that also supports streaming.
+pub struct HttpServer<I> {
+    interface: Arc<I>,
+    max_connections: usize,
+    open_connections: Arc<AtomicUsize>,
+}
+
+impl<I> HttpServer<I>
+where
+    I: Or4cl3ConversationalInterface + StreamingConversationalInterface + Send + Sync + 'static,
+{
+    pub fn new(interface: I) -> Self {
+        Self::from_shared(Arc::new(interface))
+    }
+
+    pub fn from_shared(interface: Arc<I>) -> Self {
+        Self { interface, max_connections: DEFAULT_MAX_CONNECTIONS, open_connections: Arc::new(AtomicUsize::new(0)) }
+    }
+
+    /// Connections served at once; further connections are answered with 503 and closed.
+    pub fn with_max_connections(mut self, max_connections: usize) -> Self {
+        self.max_connections = max_connections;
+        self
+    }
+
+    /// Accepts connections indefinitely, serving each on its own thread. A failed accept
+    /// (e.g. the process is out of file descriptors) is logged and the server keeps listening.
+    pub fn serve(&self, listener: TcpListener) -> io::Result<()> {
+        for stream in listener.incoming() {
+            let mut stream = match stream {
+                Ok(stream) => stream,
+                Err(e) => {
+                    eprintln!("Failed to accept a connection: {}", e);
+                    continue;
+                }
+            };
+            let Some(slot) = ConnectionSlot::acquire(&self.open_connections, self.max_connections) else {
+                let _ = stream.set_write_timeout(Some(WRITE_TIMEOUT));
+                let busy = HttpError::new(503, format!("Server is busy: {} connections open", self.max_connections));
+                let _ = write_error(&mut stream, &busy); // Best effort; the client may already be gone
+                continue;
+            };
+            let server = Self {
+                interface: self.interface.clone(),
+                max_connections: self.max_connections,
+                open_connections: self.open_connections.clone(),
+            };
+            thread::spawn(move || {
+                let _slot = slot;
+                let _ = server.handle_connection(stream); // The client went away; nothing to report to
+            });
+        }
+        Ok(())
+    }
+
+    pub fn handle_connection(&self, stream: TcpStream) -> io::Result<()> {
+        stream.set_read_timeout(Some(READ_TIMEOUT))?;
+        stream.set_write_timeout(Some(WRITE_TIMEOUT))?;
+        let mut reader = BufReader::new(stream.try_clone()?);
+        let mut writer = stream;
+        match read_request(&mut reader) {
+            Ok(request) => self.handle(&request, &mut writer),
+            Err(error) => write_error(&mut writer, &error),
+        }
+    }
+
+    /// Routes a request and writes the complete response to `out`.
+    pub fn handle(&self, request: &HttpRequest, out: &mut impl Write) -> io::Result<()> {
+        match (request.method.as_str(), request.path.as_str()) {
+            ("GET", "/health") => write_json(out, 200, &json!({ "status": "ok", "version": env!("CARGO_PKG_VERSION") })),
+            ("GET", "/schema") => write_json(out, 200, &json_schemas()),
+            ("POST", "/query") => match parse_query(request) {
+                Ok(query) => match self.interface.handle_user_query(query) {
+                    Ok(response) => match accepted_renderer(request) {
+                        Some(renderer) => match renderer.render(&response) {
+                            Ok(body) => write_body(out, 200, renderer.media_type(), body.as_bytes()),
+                            Err(message) => write_error(out, &HttpError::new(500, message)),
+                        },
+                        None => write_json(out, 200, &response),
+                    },
+                    Err(error) => write_json(out, status_for(&error), &error_body(&error)),
+                },
+                Err(error) => write_error(out, &error),
+            },
+            ("POST", "/query/stream") => match parse_query(request) {
+                Ok(query) => self.stream_query(query, out),
+                Err(error) => write_error(out, &error),
+            },
+            (_, "/health" | "/schema" | "/query" | "/query/stream") => {
+                write_error(out, &HttpError::new(405, format!("{} is not allowed on {}", request.method, request.path)))
+            }
+            _ => write_error(out, &HttpError::new(404, format!("No route for {}", request.path))),
+        }
+    }
+
+    fn stream_query(&self, query: UserQuery, out: &mut impl Write) -> io::Result<()> {
+        write!(
+            out,
+            "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nCache-Control: no-cache\r\nConnection: close\r\n\r\n"
+        )?;
+        out.flush()?;
+        // Once the client disconnects, the cycle still finishes so the session turn is recorded
+        let mut write_result = Ok(());
+        let _ = self.interface.handle_user_query_streaming(query, &mut |event| {
+            if write_result.is_ok() {
+                let (name, data) = event_payload(&event);
+                write_result = write!(out, "event: {}\ndata: {}\n\n", name, data).and_then(|_| out.flush());
+            }
+        });
+        write_result
+    }
+}
+
+/// One of the `max_connections` connections being served; the slot is freed on drop.
+struct ConnectionSlot(Arc<AtomicUsize>);
+
+impl ConnectionSlot {
+    fn acquire(open_connections: &Arc<AtomicUsize>, max_connections: usize) -> Option<Self> {
+        open_connections
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |open| (open < max_connections).then_some(open + 1))
+            .ok()
+            .map(|_| Self(open_connections.clone()))
+    }
+}
+
+impl Drop for ConnectionSlot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// First renderer named by the `Accept` header; None means the plain `SystemResponse` JSON.
+fn accepted_renderer(request: &HttpRequest) -> Option<Box<dyn super::render::ResponseRenderer + Send + Sync>> {
+    request.headers.get("accept")?.split(',').find_map(renderer_for_media_type)
+}
+
+fn parse_query(request: &HttpRequest) -> Result<UserQuery, HttpError> {
+    serde_json::from_slice(&request.body).map_err(|e| HttpError::new(400, format!("Invalid UserQuery: {}", e)))
+}
+
+/// SSE event name and JSON data for a streaming event.
+pub fn event_payload(event: &ResponseEvent) -> (&'static str, Value) {
+    match event {
+        ResponseEvent::StageStarted { stage } => ("stage_started", json!({ "stage": stage })),
+        ResponseEvent::PartialText { stage, text } => ("partial_text", json!({ "stage": stage, "text": text })),
+        ResponseEvent::UncertaintyUpdate { stage, uncertainty } => {
+            ("uncertainty_update", json!({ "stage": stage, "uncertainty": uncertainty }))
+        }
+        ResponseEvent::EthicalReflectionReady(reflection) => ("ethical_reflection", json!(reflection)),
+        ResponseEvent::Final(response) => ("final", json!(response)),
+        ResponseEvent::Failed(error) => ("error", error_body(error)),
+    }
+}
+
+fn status_for(error: &CognitionError) -> u16 {
+    match &error.kind {
+        CognitionErrorKind::InvalidInput(_) => 400,
+        CognitionErrorKind::EthicalBlock(_) => 403,
+        CognitionErrorKind::ValidationFailure(_) => 422,
+        CognitionErrorKind::Cancelled => 503,
+        CognitionErrorKind::DependencyFailure { .. } => 502,
+        CognitionErrorKind::Timeout(_) => 504,
+    }
+}
+
+fn error_body(error: &CognitionError) -> Value {
+    json!({ "error": { "message": error.to_string(), "stage": error.stage, "retryable": error.is_retryable() } })
+}
+
+fn write_error(out: &mut impl Write, error: &HttpError) -> io::Result<()> {
+    write_json(out, error.status, &json!({ "error": { "message": error.message, "stage": null, "retryable": false } }))
+}
+
+fn write_json(out: &mut impl Write, status: u16, body: &impl Serialize) -> io::Result<()> {
+    let body = serde_json::to_vec(body).map_err(io::Error::other)?;
+    write_body(out, status, "application/json", &body)
+}
+
+fn write_body(out: &mut impl Write, status: u16, content_type: &str, body: &[u8]) -> io::Result<()> {
+    write!(
+        out,
+        "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        status,
+        reason_phrase(status),
+        content_type,
+        body.len()
+    )?;
+    out.write_all(body)?;
+    out.flush()
+}
+
+fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        413 => "Payload Too Large",
+        422 => "Unprocessable Entity",
+        431 => "Request Header Fields Too Large",
+        502 => "Bad Gateway",
+        503 => "Service Unavailable",
+        504 => "Gateway Timeout",
+        _ => "Internal Server Error",
+    }
+}
+
+/// JSON Schemas (draft 2020-12) of the request and response bodies.
+pub fn json_schemas() -> Value {
+    let string = json!({ "type": "string" });
+    let optional_string = json!({ "type": ["string", "null"] });
+    let string_map = json!({ "type": ["object", "null"], "additionalProperties": { "type": "string" } });
+    json!({
+        "UserQuery": {
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "required": ["session_id", "query_id", "text"],
+            "properties": {
+                "session_id": string,
+                "query_id": string,
+                "text": string,
+                "metadata": string_map,
+            },
+        },
+        "SystemResponse": {
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "required": ["session_id", "response_to_query_id", "response_id", "text_content"],
+            "properties": {
+                "session_id": string,
+                "response_to_query_id": string,
+                "response_id": string,
+                "text_content": string,
+                "rich_content": { "type": ["array", "null"], "items": { "$ref": "#/$defs/RichContentElement" } },
+                "epistemic_uncertainty": {
+                    "type": ["object", "null"],
+                    "properties": {
+                        "level": { "type": "number", "minimum": 0.0, "maximum": 1.0 },
+                        "explanation": optional_string,
+                        "verbal_level": { "enum": ["almost_certain", "likely", "uncertain", "unlikely", "very_unlikely"] },
+                        "sources": {
+                            "type": "array",
+                            "items": {
+                                "type": "object",
+                                "properties": {
+                                    "source": {
+                                        "enum": ["missing_evidence", "conflicting_evidence", "ethical_ambiguity", "out_of_distribution"],
+                                    },
+                                    "contribution": { "type": "number", "minimum": 0.0, "maximum": 1.0 },
+                                    "explanation": string,
+                                },
+                            },
+                        },
+                    },
+                },
+                "ethical_reflection": {
+                    "type": ["object", "null"],
+                    "properties": {
+                        "pas_score_snapshot": { "type": ["number", "null"] },
+                        "summary": string,
+                        "details_query_suggestion": optional_string,
+                    },
+                },
+                "follow_up_suggestions": { "type": ["array", "null"], "items": string },
+                "diagnostic_info": string_map,
+                "locale": optional_string,
+            },
+            "$defs": {
+                "RichContentElement": {
+                    "type": "object",
+                    "required": ["type"],
+                    "properties": {
+                        "type": { "enum": ["button", "card", "link"] },
+                        "label": string,
+                        "action": string,
+                        "title": string,
+                        "text": string,
+                        "image_url": optional_string,
+                        "actions": { "type": ["array", "null"], "items": { "$ref": "#/$defs/RichContentElement" } },
+                        "url": string,
+                    },
+                },
+            },
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conversational_interface::{BasicConversationalInterface, SystemResponse};
+    use crate::recursive_cognition_engine::BasicRecursiveCognitionEngine;
+
+    fn server() -> HttpServer<BasicConversationalInterface<BasicRecursiveCognitionEngine>> {
+        HttpServer::new(BasicConversationalInterface::new(BasicRecursiveCognitionEngine::new()))
+    }
+
+    fn respond(raw: &str) -> String {
+        let server = server();
+        let mut out = Vec::new();
+        match read_request(&mut raw.as_bytes()) {
+            Ok(request) => server.handle(&request, &mut out).unwrap(),
+            Err(error) => write_error(&mut out, &error).unwrap(),
+        }
+        String::from_utf8(out).unwrap()
+    }
+
+    fn post(path: &str, body: &str) -> String {
+        respond(&format!("POST {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}", path, body.len(), body))
+    }
+
+    const QUERY: &str = r#"{"session_id": "http_session", "query_id": "q1", "text": "Tell me a fact."}"#;
+
+    #[test]
+    fn test_health_schema_and_routing_errors() {
+        let health = respond("GET /health?verbose=1 HTTP/1.1\r\n\r\n");
+        assert!(health.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(health.contains(r#""status":"ok""#));
+
+        let schema = respond("GET /schema HTTP/1.1\r\n\r\n");
+        let body: Value = serde_json::from_str(schema.split("\r\n\r\n").nth(1).unwrap()).unwrap();
+        assert_eq!(body["UserQuery"]["required"], json!(["session_id", "query_id", "text"]));
+
+        assert!(respond("GET /missing HTTP/1.1\r\n\r\n").starts_with("HTTP/1.1 404 Not Found"));
+        assert!(respond("GET /query HTTP/1.1\r\n\r\n").starts_with("HTTP/1.1 405 Method Not Allowed"));
+        assert!(post("/query", "{not json").starts_with("HTTP/1.1 400 Bad Request"));
+        let oversized = format!("POST /query HTTP/1.1\r\nContent-Length: {}\r\n\r\n", MAX_BODY_BYTES + 1);
+        assert!(respond(&oversized).starts_with("HTTP/1.1 413 Payload Too Large"));
+    }
+
+    #[test]
+    fn test_oversized_heads_and_excess_connections_are_refused() {
+        let long_line = format!("GET /health?pad={} HTTP/1.1\r\n\r\n", "a".repeat(MAX_HEADER_BYTES));
+        assert!(respond(&long_line).starts_with("HTTP/1.1 431 Request Header Fields Too Large"));
+        let many_headers = format!("GET /health HTTP/1.1\r\n{}\r\n", "X-Pad: 1\r\n".repeat(MAX_HEADERS + 1));
+        assert!(respond(&many_headers).starts_with("HTTP/1.1 431 Request Header Fields Too Large"));
+        let most_headers = format!("GET /health HTTP/1.1\r\n{}\r\n", "X-Pad: 1\r\n".repeat(MAX_HEADERS));
+        assert!(respond(&most_headers).starts_with("HTTP/1.1 200 OK"));
+
+        let open_connections = Arc::new(AtomicUsize::new(0));
+        let first = ConnectionSlot::acquire(&open_connections, 1).unwrap();
+        assert!(ConnectionSlot::acquire(&open_connections, 1).is_none());
+        drop(first);
+        assert!(ConnectionSlot::acquire(&open_connections, 1).is_some());
+        assert_eq!(open_connections.load(Ordering::Acquire), 0);
+    }
+
+    #[test]
+    fn test_query_round_trips_as_json() {
+        let raw = post("/query", QUERY);
+        assert!(raw.starts_with("HTTP/1.1 200 OK\r\nContent-Type: application/json"));
+        let response: SystemResponse = serde_json::from_str(raw.split("\r\n\r\n").nth(1).unwrap()).unwrap();
+        assert_eq!(response.response_to_query_id, "q1");
+        assert!(response.text_content.contains("(refined)"));
+
+        let markdown = respond(&format!(
+            "POST /query HTTP/1.1\r\nAccept: text/html, text/markdown\r\nContent-Length: {}\r\n\r\n{}",
+            QUERY.len(),
+            QUERY
+        ));
+        assert!(markdown.starts_with("HTTP/1.1 200 OK\r\nContent-Type: text/markdown"));
+        assert!(markdown.contains("> **Confidence:** uncertain (0.55)."));
+
+        let invalid = post("/query", r#"{"session_id": "", "query_id": "q2", "text": "Hi"}"#);
+        assert!(invalid.starts_with("HTTP/1.1 400 Bad Request"));
+        assert!(invalid.contains(r#""retryable":false"#));
+    }
+
+    #[test]
+    fn test_stream_endpoint_sends_server_sent_events() {
+        let raw = post("/query/stream", QUERY);
+        let (head, events) = raw.split_once("\r\n\r\n").unwrap();
+        assert!(head.contains("Content-Type: text/event-stream"));
+        let names: Vec<&str> = events.lines().filter_map(|line| line.strip_prefix("event: ")).collect();
+        assert_eq!(names.first(), Some(&"stage_started"));
+        assert!(names.contains(&"ethical_reflection"));
+        assert_eq!(names.last(), Some(&"final"));
+        let first_data = events.lines().find_map(|line| line.strip_prefix("data: ")).unwrap();
+        assert_eq!(first_data, r#"{"stage":"Initialization"}"#);
+    }
+}
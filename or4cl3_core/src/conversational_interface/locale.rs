@@ -0,0 +1,286 @@
+// or4cl3_core/src/conversational_interface/locale.rs
+
+//! Locale selection for queries and localized templates for the uncertainty and
+//! ethical reflection text of responses. The resolved locale is added to the
+//! stimulus metadata under `locale`, so ethical rules conditioned on it
+//! (`locale:<tag>` in a rulebook) apply to the query.
+
+use crate::recursive_cognition_engine::envelope::{canonical_locale, LOCALE_KEY};
+use crate::recursive_cognition_engine::AlignmentStatus;
+use crate::utils::tokenize;
+
+use super::uncertainty::{UncertaintySource, VerbalConfidence};
+use super::UserQuery;
+
+/// Query metadata key for the user's location; a 2-letter region code supplies the locale's region.
+pub const LOCATION_KEY: &str = "location";
+/// Locale used when a query names none and its language cannot be detected.
+pub const DEFAULT_LOCALE: &str = "en";
+
+/// Common words per language; a language is detected when at least two of its words occur
+/// and no other language has as many.
+const MARKER_WORDS: [(&str, &[&str]); 4] = [
+    ("en", &["the", "is", "are", "was", "what", "how", "why", "who", "when", "and", "of", "to", "you", "me", "tell"]),
+    ("es", &["el", "los", "las", "es", "qué", "cómo", "cuándo", "por", "para", "una", "del", "se", "quién", "dime"]),
+    ("fr", &["le", "les", "est", "quoi", "comment", "quand", "pourquoi", "une", "des", "du", "qui", "dis", "moi", "été"]),
+    ("de", &["der", "die", "das", "ist", "und", "wie", "warum", "wer", "wann", "ein", "eine", "nicht", "mir", "sag", "wurde"]),
+];
+const MIN_MARKER_WORDS: usize = 2;
+
+/// Locale for a query: its `locale` metadata if present, otherwise the detected language
+/// of its text (or `DEFAULT_LOCALE`) with the region from its `location` metadata.
+/// Fails if the `locale` metadata is not a valid language tag.
+pub fn resolve_locale(query: &UserQuery) -> Result<String, String> {
+    let metadata = query.metadata.as_ref();
+    if let Some(explicit) = metadata.and_then(|metadata| metadata.get(LOCALE_KEY)).filter(|value| !value.trim().is_empty()) {
+        return canonical_locale(explicit).map_err(|reason| format!("Query {} has invalid locale '{}': {}", query.query_id, explicit, reason));
+    }
+    let language = detect_language(&query.text).unwrap_or(DEFAULT_LOCALE);
+    let region = metadata
+        .and_then(|metadata| metadata.get(LOCATION_KEY))
+        .map(|location| location.trim())
+        .filter(|location| location.len() == 2 && location.chars().all(|c| c.is_ascii_alphabetic()));
+    Ok(match region {
+        Some(region) => format!("{}-{}", language, region.to_ascii_uppercase()),
+        None => language.to_string(),
+    })
+}
+
+/// Language of `text` among those with marker words, if one clearly dominates.
+pub fn detect_language(text: &str) -> Option<&'static str> {
+    let tokens = tokenize(text);
+    let mut counts: Vec<(&'static str, usize)> = MARKER_WORDS
+        .iter()
+        .map(|(language, words)| (*language, words.iter().filter(|word| tokens.contains(**word)).count()))
+        .collect();
+    counts.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+    match counts.as_slice() {
+        [(language, best), (_, runner_up), ..] if *best >= MIN_MARKER_WORDS && best > runner_up => Some(language),
+        _ => None,
+    }
+}
+
+/// Wording of the uncertainty and ethical reflection text in one language.
+/// `{0}`, `{1}` and `{2}` are placeholders filled by `fill`.
+pub struct Templates {
+    pub language: &'static str,
+    pub verbal: [&'static str; 5],      // VerbalConfidence, almost certain to very unlikely
+    pub grades: [&'static str; 3],      // Confidence below 0.7: moderate, low, very low
+    pub confidence_label: &'static str, // Label of the confidence disclosure
+    pub ethics_label: &'static str,     // Label of the ethics disclosure
+    pub confidence_is: &'static str,    // {0}: grade
+    pub answer_is: &'static str,        // {0}: verbal confidence
+    pub no_source: &'static str,
+    pub sources: [&'static str; 4], // UncertaintySource, capitalized
+    pub accounts_for: &'static str, // {0}: source, {1}: contribution, {2}: detail
+    pub not_text: &'static str,
+    pub unfamiliar: &'static str,
+    pub no_evidence: &'static str,
+    pub unresolved: &'static str,      // {0}: unresolved claims, {1}: all claims
+    pub contradicts: &'static str,     // {0}: claim IDs
+    pub no_assessment: &'static str,
+    pub requires_review: &'static str, // {0}: PAS
+    pub concerns: &'static str,        // {0}: number of concerns
+    pub statuses: [&'static str; 3],   // AlignmentStatus
+    pub ethics_summary: &'static str,  // {0}: status, {1}: concerns
+    pub ethics_details: &'static str,
+    pub refusal: &'static str,         // {0}: concerns, lowercased
+    pub refusal_threshold: &'static str,
+    pub rephrase: &'static str,          // Follow-up suggestion after a refusal
+    pub headings: [&'static str; 3],     // Rendered sections: evidence, actions, follow-up questions
+    pub ask: &'static str,               // {0}: follow-up questions, in short text
+    pub spoken_ask: &'static str,        // {0}: follow-up questions, joined by `spoken_or`
+    pub spoken_or: &'static str,
+}
+
+impl Templates {
+    pub fn verbal(&self, verbal: VerbalConfidence) -> &'static str {
+        self.verbal[verbal as usize]
+    }
+
+    pub fn source(&self, source: UncertaintySource) -> &'static str {
+        self.sources[source as usize]
+    }
+
+    pub fn status(&self, status: &AlignmentStatus) -> &'static str {
+        match status {
+            AlignmentStatus::Aligned => self.statuses[0],
+            AlignmentStatus::RequiresReview => self.statuses[1],
+            AlignmentStatus::Misaligned => self.statuses[2],
+        }
+    }
+}
+
+pub const ENGLISH: Templates = Templates {
+    language: "en",
+    verbal: ["almost certain", "likely", "uncertain", "unlikely", "very unlikely"],
+    grades: ["moderate", "low", "very low"],
+    confidence_label: "Confidence",
+    ethics_label: "Ethics",
+    confidence_is: "Confidence is {0}.",
+    answer_is: "The answer is {0}.",
+    no_source: "No specific source of uncertainty was identified.",
+    sources: ["Missing evidence", "Conflicting evidence", "Ethical ambiguity", "Unfamiliar input"],
+    accounts_for: "{0} accounts for {1}: {2}.",
+    not_text: "the input was not text",
+    unfamiliar: "nothing in the Mythos Memory Core resembles the question",
+    no_evidence: "no supporting evidence was linked to the answer",
+    unresolved: "{0} of {1} evidence claim(s) could not be found",
+    contradicts: "the answer contradicts evidence {0}",
+    no_assessment: "no ethical assessment was recorded",
+    requires_review: "the ethical assessment requires review (PAS {0})",
+    concerns: "the ethical assessment raised {0} concern(s)",
+    statuses: ["Aligned", "Requires Review", "Misaligned"],
+    ethics_summary: "Ethical status: {0}. Concerns: {1}.",
+    ethics_details: "Ask 'Tell me more about the ethics of this response.'",
+    refusal: "I can't help with that request because {0}.",
+    refusal_threshold: "it did not meet the ethical alignment threshold",
+    rephrase: "Rephrase your question.",
+    headings: ["Evidence", "Actions", "You could ask"],
+    ask: "Ask: {0}",
+    spoken_ask: "You could ask: {0}",
+    spoken_or: " Or: ",
+};
+
+// Meta-queries are recognized in English, so the other languages point to "Explain ethics".
+pub const SPANISH: Templates = Templates {
+    language: "es",
+    verbal: ["casi segura", "probable", "incierta", "improbable", "muy improbable"],
+    grades: ["moderada", "baja", "muy baja"],
+    confidence_label: "Confianza",
+    ethics_label: "Ética",
+    confidence_is: "La confianza es {0}.",
+    answer_is: "La respuesta es {0}.",
+    no_source: "No se identificó ninguna fuente concreta de incertidumbre.",
+    sources: ["La falta de evidencia", "La evidencia contradictoria", "La ambigüedad ética", "La entrada desconocida"],
+    accounts_for: "{0} explica {1}: {2}.",
+    not_text: "la entrada no era texto",
+    unfamiliar: "nada en el Mythos Memory Core se parece a la pregunta",
+    no_evidence: "no se vinculó evidencia de apoyo a la respuesta",
+    unresolved: "no se encontraron {0} de {1} afirmaciones de evidencia",
+    contradicts: "la respuesta contradice la evidencia {0}",
+    no_assessment: "no se registró ninguna evaluación ética",
+    requires_review: "la evaluación ética requiere revisión (PAS {0})",
+    concerns: "la evaluación ética planteó {0} inquietud(es)",
+    statuses: ["Alineado", "Requiere revisión", "Desalineado"],
+    ethics_summary: "Estado ético: {0}. Inquietudes: {1}.",
+    ethics_details: "Escribe 'Explain ethics' para ver los detalles.",
+    refusal: "No puedo ayudar con esa solicitud porque {0}.",
+    refusal_threshold: "no alcanzó el umbral de alineación ética",
+    rephrase: "Reformula tu pregunta.",
+    headings: ["Evidencia", "Acciones", "Podrías preguntar"],
+    ask: "Pregunta: {0}",
+    spoken_ask: "Podrías preguntar: {0}",
+    spoken_or: " O: ",
+};
+
+pub const FRENCH: Templates = Templates {
+    language: "fr",
+    verbal: ["presque certaine", "probable", "incertaine", "improbable", "très improbable"],
+    grades: ["modérée", "faible", "très faible"],
+    confidence_label: "Confiance",
+    ethics_label: "Éthique",
+    confidence_is: "La confiance est {0}.",
+    answer_is: "La réponse est {0}.",
+    no_source: "Aucune source d'incertitude précise n'a été identifiée.",
+    sources: ["Le manque de preuves", "Les preuves contradictoires", "L'ambiguïté éthique", "L'entrée inhabituelle"],
+    accounts_for: "{0} représente {1} : {2}.",
+    not_text: "l'entrée n'était pas du texte",
+    unfamiliar: "rien dans le Mythos Memory Core ne ressemble à la question",
+    no_evidence: "aucune preuve n'a été associée à la réponse",
+    unresolved: "{0} preuve(s) sur {1} sont introuvables",
+    contradicts: "la réponse contredit la preuve {0}",
+    no_assessment: "aucune évaluation éthique n'a été enregistrée",
+    requires_review: "l'évaluation éthique doit être revue (PAS {0})",
+    concerns: "l'évaluation éthique a soulevé {0} préoccupation(s)",
+    statuses: ["Aligné", "Révision requise", "Non aligné"],
+    ethics_summary: "Statut éthique : {0}. Préoccupations : {1}.",
+    ethics_details: "Écrivez 'Explain ethics' pour plus de détails.",
+    refusal: "Je ne peux pas répondre à cette demande car {0}.",
+    refusal_threshold: "elle n'atteint pas le seuil d'alignement éthique",
+    rephrase: "Reformulez votre question.",
+    headings: ["Preuves", "Actions", "Vous pourriez demander"],
+    ask: "Demandez : {0}",
+    spoken_ask: "Vous pourriez demander : {0}",
+    spoken_or: " Ou : ",
+};
+
+pub const GERMAN: Templates = Templates {
+    language: "de",
+    verbal: ["nahezu sicher", "wahrscheinlich", "unsicher", "unwahrscheinlich", "sehr unwahrscheinlich"],
+    grades: ["mäßig", "niedrig", "sehr niedrig"],
+    confidence_label: "Konfidenz",
+    ethics_label: "Ethik",
+    confidence_is: "Die Konfidenz ist {0}.",
+    answer_is: "Die Antwort ist {0}.",
+    no_source: "Es wurde keine bestimmte Quelle der Unsicherheit gefunden.",
+    sources: ["Fehlende Belege", "Widersprüchliche Belege", "Ethische Mehrdeutigkeit", "Ungewohnte Eingabe"],
+    accounts_for: "{0} – Anteil {1}: {2}.",
+    not_text: "die Eingabe war kein Text",
+    unfamiliar: "nichts im Mythos Memory Core ähnelt der Frage",
+    no_evidence: "der Antwort wurden keine Belege zugeordnet",
+    unresolved: "{0} von {1} Belegen wurden nicht gefunden",
+    contradicts: "die Antwort widerspricht dem Beleg {0}",
+    no_assessment: "es wurde keine ethische Bewertung erfasst",
+    requires_review: "die ethische Bewertung muss geprüft werden (PAS {0})",
+    concerns: "die ethische Bewertung ergab {0} Bedenken",
+    statuses: ["Ausgerichtet", "Prüfung erforderlich", "Nicht ausgerichtet"],
+    ethics_summary: "Ethischer Status: {0}. Bedenken: {1}.",
+    ethics_details: "Schreiben Sie 'Explain ethics' für Details.",
+    refusal: "Bei dieser Anfrage kann ich nicht helfen, weil {0}.",
+    refusal_threshold: "sie die Schwelle für ethische Ausrichtung nicht erreicht hat",
+    rephrase: "Formulieren Sie Ihre Frage um.",
+    headings: ["Belege", "Aktionen", "Sie könnten fragen"],
+    ask: "Fragen Sie: {0}",
+    spoken_ask: "Sie könnten fragen: {0}",
+    spoken_or: " Oder: ",
+};
+
+const TEMPLATES: [&Templates; 4] = [&ENGLISH, &SPANISH, &FRENCH, &GERMAN];
+
+/// Templates for the language of `locale` (e.g. "es" for "es-MX"); English for other languages.
+pub fn templates_for(locale: &str) -> &'static Templates {
+    let language = locale.split(['-', '_']).next().unwrap_or_default().to_ascii_lowercase();
+    TEMPLATES.into_iter().find(|templates| templates.language == language).unwrap_or(&ENGLISH)
+}
+
+/// `template` with `{0}`, `{1}`, ... replaced by `args`.
+pub fn fill(template: &str, args: &[&str]) -> String {
+    args.iter()
+        .enumerate()
+        .fold(template.to_string(), |text, (index, arg)| text.replace(&format!("{{{}}}", index), arg))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn query(text: &str, metadata: &[(&str, &str)]) -> UserQuery {
+        UserQuery {
+            session_id: "s1".to_string(),
+            query_id: "q1".to_string(),
+            text: text.to_string(),
+            metadata: Some(metadata.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect::<HashMap<_, _>>()),
+        }
+    }
+
+    #[test]
+    fn test_locale_comes_from_metadata_then_detection_and_location() {
+        assert_eq!(resolve_locale(&query("Hola", &[("locale", "pt_br")])), Ok("pt-BR".to_string()));
+        assert!(resolve_locale(&query("Hola", &[("locale", "spanish")])).unwrap_err().contains("invalid locale 'spanish'"));
+        assert_eq!(resolve_locale(&query("¿Cuándo se firmó el tratado?", &[("location", "mx")])), Ok("es-MX".to_string()));
+        assert_eq!(resolve_locale(&query("Wann wurde der Vertrag unterzeichnet?", &[("location", "Berlin")])), Ok("de".to_string()));
+        assert_eq!(resolve_locale(&query("Westphalia 1648", &[])), Ok(DEFAULT_LOCALE.to_string()));
+        assert_eq!(detect_language("Pourquoi le traité est-il important ?"), Some("fr"));
+        assert_eq!(detect_language("What is the treaty of Westphalia?"), Some("en"));
+    }
+
+    #[test]
+    fn test_templates_follow_the_language_of_the_locale() {
+        assert_eq!(templates_for("es-MX").language, "es");
+        assert_eq!(templates_for("ja").language, "en");
+        assert_eq!(templates_for("de").verbal(VerbalConfidence::Likely), "wahrscheinlich");
+        assert_eq!(fill(FRENCH.accounts_for, &["L'ambiguïté éthique", "0.30", "x"]), "L'ambiguïté éthique représente 0.30 : x.");
+    }
+}
@@ -0,0 +1,231 @@
+// or4cl3_core/src/conversational_interface/meta.rs
+
+//! Follow-up questions about an earlier response ("Tell me more about the ethics
+//! of this response", "Why are you uncertain about that?"). They are answered from the
+//! `TurnOutcome` the session stored for that response instead of starting a new
+//! cognition cycle.
+
+use std::fmt;
+
+use crate::recursive_cognition_engine::CognitiveState;
+
+use super::epistemic_uncertainty;
+use super::session::TurnOutcome;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetaQuery {
+    Ethics,      // Concerns, mitigations and PAS breakdown, or why the query was refused
+    Uncertainty, // Confidence, its explanation, and the steps that changed it
+    Evidence,    // Supporting evidence the response was linked to
+}
+
+impl fmt::Display for MetaQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MetaQuery::Ethics => write!(f, "ethics"),
+            MetaQuery::Uncertainty => write!(f, "uncertainty"),
+            MetaQuery::Evidence => write!(f, "evidence"),
+        }
+    }
+}
+
+/// A meta-query opens with one of these and is at most `MAX_META_QUERY_WORDS` long.
+const LEADS: &[&str] = &["explain", "tell me more", "why", "how", "what", "show", "which"];
+/// Words that make a question refer to an earlier response rather than to a new topic.
+/// "you" alone is not one: "What do you think about ethics?" asks about a new topic.
+const REFERENTS: &[&str] = &["this", "that", "last", "previous", "response", "answer"];
+/// Checked in order; the first topic with a matching keyword wins. Keywords match at word starts.
+const TOPICS: &[(MetaQuery, &[&str])] = &[
+    (MetaQuery::Ethics, &["ethic", "refus", "concern", "can't help", "cannot help", "won't help"]),
+    (MetaQuery::Uncertainty, &["uncertain", "unsure", "confiden", "sure"]),
+    (MetaQuery::Evidence, &["evidence", "source", "based on"]),
+];
+const MAX_META_QUERY_WORDS: usize = 12;
+/// Shorter questions need no referent, e.g. "Explain ethics".
+const BARE_META_QUERY_WORDS: usize = 3;
+
+impl MetaQuery {
+    /// Recognizes a meta-query and the response it names, if it names one by ID
+    /// (e.g. "Explain the ethics of response_to_q1"); otherwise it refers to the latest response.
+    pub fn detect(text: &str) -> Option<(MetaQuery, Option<String>)> {
+        let normalized: String = text
+            .to_lowercase()
+            .chars()
+            .map(|c| if c.is_alphanumeric() || c == '\'' || c == '_' { c } else { ' ' })
+            .collect();
+        let words: Vec<&str> = normalized.split_whitespace().collect();
+        if words.is_empty() || words.len() > MAX_META_QUERY_WORDS {
+            return None;
+        }
+        let spaced = format!(" {} ", words.join(" "));
+        if !LEADS.iter().any(|lead| spaced.starts_with(&format!(" {} ", lead))) {
+            return None;
+        }
+        let target = text
+            .split_whitespace()
+            .map(|token| token.trim_matches(|c: char| !c.is_alphanumeric() && c != '_' && c != '-'))
+            .find(|token| token.starts_with("response_to_"))
+            .map(str::to_string);
+        let refers_back = target.is_some()
+            || words.len() <= BARE_META_QUERY_WORDS
+            || words.iter().any(|word| REFERENTS.contains(word));
+        if !refers_back {
+            return None;
+        }
+        let meta_query = TOPICS
+            .iter()
+            .find(|(_, keywords)| keywords.iter().any(|keyword| spaced.contains(&format!(" {}", keyword))))
+            .map(|(meta_query, _)| *meta_query)?;
+        Some((meta_query, target))
+    }
+
+    /// Plain-text answer about the response `response_id`, which had `outcome`.
+    pub fn explain(&self, response_id: &str, outcome: &TurnOutcome) -> String {
+        match (self, outcome) {
+            (MetaQuery::Ethics, TurnOutcome::Answered(state)) => explain_ethics(response_id, state),
+            (MetaQuery::Ethics, TurnOutcome::Refused(reason)) => {
+                let mut lines = vec![format!(
+                    "I declined to answer {}: its PAS of {:.2} was below the review threshold of {:.2}.",
+                    response_id, reason.pas_score, reason.thresholds.requires_review
+                )];
+                lines.extend(bulleted("Concerns", &reason.ethical_concerns));
+                lines.extend(bulleted("Triggered rules", &reason.triggered_rules));
+                lines.join("\n")
+            }
+            (MetaQuery::Uncertainty, TurnOutcome::Answered(state)) => explain_uncertainty(response_id, state),
+            (MetaQuery::Evidence, TurnOutcome::Answered(state)) => explain_evidence(response_id, state),
+            (MetaQuery::Uncertainty | MetaQuery::Evidence, TurnOutcome::Refused(_)) => format!(
+                "{} was declined on ethical grounds, so no answer, confidence or evidence was given. Ask about its ethics for the reasons.",
+                response_id
+            ),
+        }
+    }
+}
+
+fn explain_ethics(response_id: &str, state: &CognitiveState) -> String {
+    let Some(assessment) = &state.ethical_assessment else {
+        return format!("No ethical assessment was recorded for {}.", response_id);
+    };
+    let mut lines = vec![format!(
+        "Ethical assessment of {}: {} with a PAS of {:.2}.",
+        response_id, assessment.alignment_status, assessment.pas_score
+    )];
+    lines.extend(bulleted("Concerns", &assessment.ethical_concerns));
+    lines.extend(bulleted("Suggested mitigations", &assessment.suggested_mitigations));
+    let mut breakdown: Vec<(&String, &f64)> = assessment.pas_breakdown.iter().collect();
+    breakdown.sort_by(|(a_rule, a), (b_rule, b)| b.total_cmp(a).then_with(|| a_rule.cmp(b_rule)));
+    let breakdown: Vec<String> = breakdown.into_iter().map(|(rule, penalty)| format!("{}: -{:.2}", rule, penalty)).collect();
+    lines.extend(bulleted("PAS breakdown", &breakdown));
+    lines.join("\n")
+}
+
+fn explain_uncertainty(response_id: &str, state: &CognitiveState) -> String {
+    let uncertainty = epistemic_uncertainty(state);
+    let mut lines = vec![format!("My confidence in {} was {:.2}.", response_id, uncertainty.level)];
+    match uncertainty.explanation {
+        Some(explanation) => lines.push(explanation),
+        None => {
+            lines.push("That is high enough that no caveat was needed.".to_string());
+            let sources: Vec<String> = uncertainty
+                .sources
+                .iter()
+                .map(|source| format!("{}: {:.2} ({})", source.source, source.contribution, source.explanation))
+                .collect();
+            lines.extend(bulleted("Remaining uncertainty", &sources));
+        }
+    }
+    let steps: Vec<String> = state
+        .history_log
+        .iter()
+        .filter(|entry| entry.to_lowercase().contains("confidence"))
+        .cloned()
+        .collect();
+    lines.extend(bulleted("Steps that adjusted it", &steps));
+    lines.push(explain_evidence(response_id, state));
+    lines.join("\n")
+}
+
+fn explain_evidence(response_id: &str, state: &CognitiveState) -> String {
+    if state.supporting_evidence_ids.is_empty() {
+        format!("No supporting evidence was linked to {}.", response_id)
+    } else {
+        format!("Supporting evidence for {}: {}.", response_id, state.supporting_evidence_ids.join(", "))
+    }
+}
+
+/// "Heading:" followed by one "- item" line per item, or "Heading: none." when empty.
+fn bulleted(heading: &str, items: &[String]) -> Vec<String> {
+    if items.is_empty() {
+        return vec![format!("{}: none.", heading)];
+    }
+    let mut lines = vec![format!("{}:", heading)];
+    lines.extend(items.iter().map(|item| format!("- {}", item)));
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    use crate::conversational_interface::{BasicConversationalInterface, Or4cl3ConversationalInterface, UserQuery};
+    use crate::polyethical_manifold::{EthicalRulebook, RulebookEthicalAssessor};
+    use crate::recursive_cognition_engine::{BasicRecursiveCognitionEngine, PasThresholds};
+
+    #[test]
+    fn test_detects_meta_queries_that_refer_back() {
+        assert_eq!(MetaQuery::detect("Tell me more about the ethics of this response."), Some((MetaQuery::Ethics, None)));
+        assert_eq!(MetaQuery::detect("Why are you uncertain about that?"), Some((MetaQuery::Uncertainty, None)));
+        assert_eq!(MetaQuery::detect("Explain ethics"), Some((MetaQuery::Ethics, None)));
+        assert_eq!(MetaQuery::detect("Why did you refuse that?"), Some((MetaQuery::Ethics, None)));
+        assert_eq!(MetaQuery::detect("What is your answer based on?"), Some((MetaQuery::Evidence, None)));
+        assert_eq!(
+            MetaQuery::detect("Explain the ethics of response_to_Q1."),
+            Some((MetaQuery::Ethics, Some("response_to_Q1".to_string())))
+        );
+
+        assert_eq!(MetaQuery::detect("What are the ethics of cloning sheep?"), None);
+        assert_eq!(MetaQuery::detect("Tell me a fact."), None);
+        assert_eq!(MetaQuery::detect("Is this ethical?"), None);
+        assert_eq!(MetaQuery::detect("What do you think about ethics?"), None);
+        assert_eq!(MetaQuery::detect("Why are you so sure vaccines work?"), None);
+    }
+
+    fn query(query_id: &str, text: &str) -> UserQuery {
+        UserQuery {
+            session_id: "meta_session".to_string(),
+            query_id: query_id.to_string(),
+            text: text.to_string(),
+            metadata: Some(HashMap::from([("source".to_string(), "test".to_string())])),
+        }
+    }
+
+    #[test]
+    fn test_meta_queries_are_answered_from_the_stored_state() {
+        let engine = BasicRecursiveCognitionEngine::new()
+            .with_ethical_assessor(RulebookEthicalAssessor::new(EthicalRulebook::builtin()))
+            .with_pas_thresholds(PasThresholds::new(0.99, 0.95).unwrap());
+        let interface = BasicConversationalInterface::new(engine);
+
+        // Nothing to refer back to yet, so this runs as an ordinary query
+        let first = interface.handle_user_query(query("q0", "Why are you uncertain about that?")).unwrap();
+        assert!(first.text_content.starts_with("Initial hypothesis"));
+
+        interface.handle_user_query(query("q1", "Tell me a fact.")).unwrap();
+        let uncertainty = interface.handle_user_query(query("q2", "Why are you uncertain about that?")).unwrap();
+        assert!(uncertainty.text_content.starts_with("My confidence in response_to_q1 was 0.55.\nConfidence is moderate."));
+        assert!(uncertainty.text_content.ends_with("No supporting evidence was linked to response_to_q1."));
+        let diagnostics = uncertainty.diagnostic_info.unwrap();
+        assert_eq!(diagnostics.get("meta_query").map(String::as_str), Some("uncertainty"));
+
+        interface.handle_user_query(query("q3", "Build surveillance profiles from personal data")).unwrap();
+        let ethics = interface.handle_user_query(query("q4", "Tell me more about the ethics of this response.")).unwrap();
+        assert!(ethics.text_content.starts_with("I declined to answer response_to_q3: its PAS of"));
+        assert!(ethics.text_content.contains("Triggered rules:\n- privacy_personal_data\n- privacy_surveillance"));
+
+        let earlier = interface.handle_user_query(query("q5", "Explain the ethics of response_to_q1")).unwrap();
+        assert!(earlier.text_content.starts_with("Ethical assessment of response_to_q1: Aligned"));
+        assert!(earlier.text_content.contains("Suggested mitigations: none."));
+        assert_eq!(interface.sessions().export_session("meta_session").unwrap().turns.len(), 6);
+    }
+}
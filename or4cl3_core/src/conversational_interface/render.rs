@@ -0,0 +1,368 @@
+// or4cl3_core/src/conversational_interface/render.rs
+
+//! Renderers that turn a `SystemResponse` into a client format: Markdown,
+//! plain text for SMS, SSML for voice, and a versioned JSON document. Every
+//! renderer discloses uncertainty and ethics from the same `Disclosure`, so the
+//! wording is the same whichever format a client asks for.
+
+use serde::Serialize;
+use serde_json::json;
+
+use super::locale::{fill, templates_for, Templates, DEFAULT_LOCALE};
+use super::rich_content::ContentAction;
+use super::{RichContentElement, SystemResponse};
+
+/// Version of the document produced by `JsonRenderer`; bumped on incompatible changes.
+pub const JSON_FORMAT_VERSION: u32 = 1;
+/// Names accepted by `renderer_for`.
+pub const FORMATS: [&str; 4] = ["markdown", "text", "ssml", "json"];
+
+pub trait ResponseRenderer {
+    /// Media type of the rendered output, e.g. "text/markdown".
+    fn media_type(&self) -> &'static str;
+    fn render(&self, response: &SystemResponse) -> Result<String, String>;
+}
+
+/// Renderer for one of `FORMATS`, with its default settings.
+pub fn renderer_for(format: &str) -> Option<Box<dyn ResponseRenderer + Send + Sync>> {
+    match format {
+        "markdown" => Some(Box::new(MarkdownRenderer)),
+        "text" => Some(Box::new(PlainTextRenderer::default())),
+        "ssml" => Some(Box::new(SsmlRenderer)),
+        "json" => Some(Box::new(JsonRenderer)),
+        _ => None,
+    }
+}
+
+/// Renderer whose media type is `media_type`, ignoring parameters such as "; charset=utf-8".
+pub fn renderer_for_media_type(media_type: &str) -> Option<Box<dyn ResponseRenderer + Send + Sync>> {
+    let media_type = media_type.split(';').next().unwrap_or_default().trim();
+    FORMATS
+        .iter()
+        .filter_map(|format| renderer_for(format))
+        .find(|renderer| renderer.media_type().eq_ignore_ascii_case(media_type))
+}
+
+/// Uncertainty and ethics statements for a response, worded the same in every format
+/// and written for the response's locale.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Disclosure {
+    pub confidence: Option<String>,         // "Confidence: likely (0.78).", labelled in the response's language
+    pub uncertainty_detail: Option<String>, // Why confidence is not higher, when it is low
+    pub ethics: Option<String>,             // Ethical reflection summary with its PAS
+}
+
+impl Disclosure {
+    pub fn of(response: &SystemResponse) -> Self {
+        let templates = templates_of(response);
+        let uncertainty = response.epistemic_uncertainty.as_ref();
+        Self {
+            confidence: uncertainty.map(|uncertainty| {
+                format!("{}: {} ({:.2}).", templates.confidence_label, templates.verbal(uncertainty.verbal_level), uncertainty.level)
+            }),
+            uncertainty_detail: uncertainty.and_then(|uncertainty| uncertainty.explanation.clone()),
+            ethics: response.ethical_reflection.as_ref().map(|reflection| match reflection.pas_score_snapshot {
+                Some(pas) => format!("{} PAS {:.2}.", reflection.summary, pas),
+                None => reflection.summary.clone(),
+            }),
+        }
+    }
+}
+
+fn templates_of(response: &SystemResponse) -> &'static Templates {
+    templates_for(response.locale.as_deref().unwrap_or(DEFAULT_LOCALE))
+}
+
+/// Follow-up questions offered by a response: its Ask buttons, then suggestions without a button.
+fn follow_ups(response: &SystemResponse) -> Vec<String> {
+    let mut questions: Vec<String> = response
+        .rich_content
+        .iter()
+        .flatten()
+        .filter_map(|element| match element {
+            RichContentElement::Button { action, .. } => match ContentAction::parse(action) {
+                Ok(ContentAction::Ask { text }) => Some(text),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+    for suggestion in response.follow_up_suggestions.iter().flatten() {
+        if !questions.contains(suggestion) {
+            questions.push(suggestion.clone());
+        }
+    }
+    questions
+}
+
+/// Markdown with the disclosure as a block quote and rich content as lists of links and actions.
+pub struct MarkdownRenderer;
+
+impl ResponseRenderer for MarkdownRenderer {
+    fn media_type(&self) -> &'static str {
+        "text/markdown"
+    }
+
+    fn render(&self, response: &SystemResponse) -> Result<String, String> {
+        let mut sections = vec![response.text_content.clone()];
+
+        let disclosure = Disclosure::of(response);
+        let templates = templates_of(response);
+        let mut quoted = Vec::new();
+        if let Some(confidence) = &disclosure.confidence {
+            let detail = disclosure.uncertainty_detail.as_deref().map(|detail| format!(" {}", detail)).unwrap_or_default();
+            let label = templates.confidence_label;
+            let confidence = confidence.trim_start_matches(label).trim_start_matches(": ");
+            quoted.push(format!("> **{}:** {}{}", label, confidence, detail));
+        }
+        if let Some(ethics) = &disclosure.ethics {
+            quoted.push(format!("> **{}:** {}", templates.ethics_label, ethics));
+        }
+        if !quoted.is_empty() {
+            sections.push(quoted.join("\n>\n"));
+        }
+
+        let mut evidence = Vec::new();
+        let mut actions = Vec::new();
+        for element in response.rich_content.iter().flatten() {
+            match element {
+                RichContentElement::Card { title, text, actions: links, .. } => {
+                    let links: Vec<String> = links.iter().flatten().filter_map(markdown_link).collect();
+                    let links = if links.is_empty() { String::new() } else { format!(" ({})", links.join(", ")) };
+                    evidence.push(format!("- **{}**: {}{}", title, text, links));
+                }
+                RichContentElement::Link { .. } => evidence.extend(markdown_link(element).map(|link| format!("- {}", link))),
+                RichContentElement::Button { label, action } => {
+                    if !matches!(ContentAction::parse(action), Ok(ContentAction::Ask { .. })) {
+                        actions.push(format!("- {}: `{}`", label, action));
+                    }
+                }
+            }
+        }
+        let questions: Vec<String> = follow_ups(response).iter().map(|question| format!("- {}", question)).collect();
+        for (heading, items) in templates.headings.into_iter().zip([evidence, actions, questions]) {
+            if !items.is_empty() {
+                sections.push(format!("**{}**\n{}", heading, items.join("\n")));
+            }
+        }
+        Ok(sections.join("\n\n"))
+    }
+}
+
+fn markdown_link(element: &RichContentElement) -> Option<String> {
+    match element {
+        RichContentElement::Link { text, url } => Some(format!("[{}]({})", text, url)),
+        _ => None,
+    }
+}
+
+/// Plain text within `max_chars`, e.g. for SMS. Follow-up questions are dropped first, then the
+/// answer is shortened; the disclosure is never cut, so a limit too small for it yields the
+/// disclosure alone. Evidence and feedback buttons are left out.
+pub struct PlainTextRenderer {
+    pub max_chars: usize,
+}
+
+impl Default for PlainTextRenderer {
+    fn default() -> Self {
+        Self { max_chars: 320 } // Two 160-character SMS messages
+    }
+}
+
+impl ResponseRenderer for PlainTextRenderer {
+    fn media_type(&self) -> &'static str {
+        "text/plain"
+    }
+
+    fn render(&self, response: &SystemResponse) -> Result<String, String> {
+        let disclosure = Disclosure::of(response);
+        let disclosure: Vec<String> = [disclosure.confidence, disclosure.ethics].into_iter().flatten().collect();
+        let questions = follow_ups(response);
+        let join = |lines: &[&str]| lines.iter().filter(|line| !line.is_empty()).copied().collect::<Vec<_>>().join("\n");
+
+        let asked = if questions.is_empty() { String::new() } else { fill(templates_of(response).ask, &[&questions.join(" | ")]) };
+        let disclosure = disclosure.join("\n");
+        let complete = join(&[&response.text_content, &disclosure, &asked]);
+        if complete.chars().count() <= self.max_chars {
+            return Ok(complete);
+        }
+        let separator = usize::from(!disclosure.is_empty());
+        let budget = self.max_chars.saturating_sub(disclosure.chars().count() + separator);
+        let text = if budget > "...".len() { truncate(&response.text_content, budget) } else { String::new() };
+        Ok(join(&[&text, &disclosure]))
+    }
+}
+
+/// At most `max_chars` characters, cut at a word boundary where possible and ending in "..." when shortened.
+fn truncate(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let kept: String = text.chars().take(max_chars.saturating_sub(3) + 1).collect();
+    let kept = match kept.rfind(char::is_whitespace) {
+        Some(boundary) if boundary > 0 => &kept[..boundary],
+        _ => &kept[..kept.char_indices().last().map(|(index, _)| index).unwrap_or(0)],
+    };
+    format!("{}...", kept.trim_end()).chars().take(max_chars).collect()
+}
+
+/// SSML 1.1 for speech synthesis in the response's locale: the answer, a pause, the disclosure,
+/// then follow-up questions.
+pub struct SsmlRenderer;
+
+impl ResponseRenderer for SsmlRenderer {
+    fn media_type(&self) -> &'static str {
+        "application/ssml+xml"
+    }
+
+    fn render(&self, response: &SystemResponse) -> Result<String, String> {
+        let disclosure = Disclosure::of(response);
+        let mut paragraphs = vec![response.text_content.clone()];
+        let confidence: Vec<String> = [disclosure.confidence, disclosure.uncertainty_detail].into_iter().flatten().collect();
+        if !confidence.is_empty() {
+            paragraphs.push(confidence.join(" "));
+        }
+        paragraphs.extend(disclosure.ethics);
+        let questions = follow_ups(response);
+        if !questions.is_empty() {
+            let templates = templates_of(response);
+            paragraphs.push(fill(templates.spoken_ask, &[&questions.join(templates.spoken_or)]));
+        }
+        let body: Vec<String> = paragraphs.iter().map(|paragraph| format!("<p>{}</p>", xml_escape(paragraph))).collect();
+        let locale = response.locale.as_deref().unwrap_or(DEFAULT_LOCALE);
+        Ok(format!(
+            "<speak version=\"1.1\" xmlns=\"http://www.w3.org/2001/10/synthesis\" xml:lang=\"{}\">{}</speak>",
+            xml_escape(locale),
+            body.join("<break time=\"400ms\"/>")
+        ))
+    }
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// `{"format_version", "response", "disclosure"}`: the response as served by `/query`,
+/// with the disclosure every other renderer shows.
+pub struct JsonRenderer;
+
+impl ResponseRenderer for JsonRenderer {
+    fn media_type(&self) -> &'static str {
+        "application/vnd.or4cl3.response+json"
+    }
+
+    fn render(&self, response: &SystemResponse) -> Result<String, String> {
+        let document = json!({
+            "format_version": JSON_FORMAT_VERSION,
+            "response": response,
+            "disclosure": Disclosure::of(response),
+        });
+        serde_json::to_string_pretty(&document).map_err(|e| format!("Failed to render response {}: {}", response.response_id, e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conversational_interface::uncertainty::VerbalConfidence;
+    use crate::conversational_interface::{EpistemicUncertaintyInfo, EthicalReflectionInfo};
+
+    fn response() -> SystemResponse {
+        SystemResponse {
+            session_id: "s1".to_string(),
+            response_to_query_id: "q1".to_string(),
+            response_id: "response_to_q1".to_string(),
+            text_content: "The treaty was signed in 1648 & ratified later.".to_string(),
+            rich_content: Some(vec![
+                RichContentElement::Card {
+                    title: "Westphalia archives".to_string(),
+                    text: "The treaty was signed in 1648.".to_string(),
+                    image_url: None,
+                    actions: Some(vec![RichContentElement::Link {
+                        text: "View claim".to_string(),
+                        url: "mythos://claim/claim_treaty".to_string(),
+                    }]),
+                },
+                RichContentElement::Button { label: "Agree".to_string(), action: "feedback:agree:response_to_q1".to_string() },
+                RichContentElement::Button { label: "Who signed it?".to_string(), action: "ask:Who signed it?".to_string() },
+            ]),
+            epistemic_uncertainty: Some(EpistemicUncertaintyInfo {
+                level: 0.55,
+                explanation: Some("Confidence is moderate.".to_string()),
+                verbal_level: VerbalConfidence::Uncertain,
+                sources: vec![],
+            }),
+            ethical_reflection: Some(EthicalReflectionInfo {
+                pas_score_snapshot: Some(0.98),
+                summary: "Ethical status: Aligned.".to_string(),
+                details_query_suggestion: None,
+            }),
+            follow_up_suggestions: Some(vec!["Who signed it?".to_string(), "Where?".to_string()]),
+            diagnostic_info: None,
+            locale: Some("en-GB".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_markdown_and_ssml_embed_the_same_disclosure() {
+        let markdown = renderer_for("markdown").unwrap().render(&response()).unwrap();
+        assert_eq!(
+            markdown,
+            "The treaty was signed in 1648 & ratified later.\n\n\
+             > **Confidence:** uncertain (0.55). Confidence is moderate.\n>\n\
+             > **Ethics:** Ethical status: Aligned. PAS 0.98.\n\n\
+             **Evidence**\n- **Westphalia archives**: The treaty was signed in 1648. ([View claim](mythos://claim/claim_treaty))\n\n\
+             **Actions**\n- Agree: `feedback:agree:response_to_q1`\n\n\
+             **You could ask**\n- Who signed it?\n- Where?"
+        );
+
+        let ssml = renderer_for_media_type("application/ssml+xml; charset=utf-8").unwrap().render(&response()).unwrap();
+        assert_eq!(
+            ssml,
+            "<speak version=\"1.1\" xmlns=\"http://www.w3.org/2001/10/synthesis\" xml:lang=\"en-GB\">\
+             <p>The treaty was signed in 1648 &amp; ratified later.</p><break time=\"400ms\"/>\
+             <p>Confidence: uncertain (0.55). Confidence is moderate.</p><break time=\"400ms\"/>\
+             <p>Ethical status: Aligned. PAS 0.98.</p><break time=\"400ms\"/>\
+             <p>You could ask: Who signed it? Or: Where?</p></speak>"
+        );
+        assert!(renderer_for("html").is_none());
+
+        let spanish = SystemResponse { locale: Some("es-MX".to_string()), ..response() };
+        assert!(renderer_for("markdown").unwrap().render(&spanish).unwrap().contains("> **Ética:** Ethical status"));
+        assert!(renderer_for("markdown").unwrap().render(&spanish).unwrap().ends_with("**Podrías preguntar**\n- Who signed it?\n- Where?"));
+        let spoken = renderer_for("ssml").unwrap().render(&spanish).unwrap();
+        assert!(spoken.contains("xml:lang=\"es-MX\">"));
+        assert!(spoken.contains("<p>Podrías preguntar: Who signed it? O: Where?</p>"));
+    }
+
+    #[test]
+    fn test_plain_text_fits_its_limit_and_json_is_versioned() {
+        let text = PlainTextRenderer { max_chars: 120 }.render(&response()).unwrap();
+        assert_eq!(
+            text,
+            "The treaty was signed in 1648 & ratified later.\nConfidence: uncertain (0.55).\nEthical status: Aligned. PAS 0.98."
+        );
+        assert!(text.chars().count() <= 120);
+        let short = PlainTextRenderer { max_chars: 90 }.render(&response()).unwrap();
+        assert_eq!(short, "The treaty was signed...\nConfidence: uncertain (0.55).\nEthical status: Aligned. PAS 0.98.");
+        assert!(short.chars().count() <= 90);
+        let full = PlainTextRenderer::default().render(&response()).unwrap();
+        assert!(full.ends_with("PAS 0.98.\nAsk: Who signed it? | Where?"));
+        let german = SystemResponse { locale: Some("de".to_string()), ..response() };
+        assert!(PlainTextRenderer::default().render(&german).unwrap().ends_with("\nFragen Sie: Who signed it? | Where?"));
+        // Too small even for the disclosure: it is sent whole rather than cut
+        assert_eq!(
+            PlainTextRenderer { max_chars: 10 }.render(&response()).unwrap(),
+            "Confidence: uncertain (0.55).\nEthical status: Aligned. PAS 0.98."
+        );
+
+        let json: serde_json::Value = serde_json::from_str(&JsonRenderer.render(&response()).unwrap()).unwrap();
+        assert_eq!(json["format_version"], JSON_FORMAT_VERSION);
+        assert_eq!(json["response"]["epistemic_uncertainty"]["verbal_level"], "uncertain");
+        assert_eq!(json["disclosure"]["ethics"], "Ethical status: Aligned. PAS 0.98.");
+    }
+}
@@ -0,0 +1,245 @@
+// or4cl3_core/src/conversational_interface/repl.rs
+
+//! Interactive chat loop behind the `or4cl3` binary.
+//! Lines are sent to a `BasicConversationalInterface` as queries and the
+//! responses are printed with their epistemic uncertainty and ethical
+//! reflection inline. Lines starting with `/` are commands (see `HELP`).
+
+use std::io::{self, BufRead, Write};
+use std::sync::{Arc, Mutex};
+
+use crate::recursive_cognition_engine::hooks::{HookDecision, HookedEngine, StageHook};
+use crate::recursive_cognition_engine::{CognitionStage, CognitiveState, RecursiveCognitionEngine};
+
+use super::{BasicConversationalInterface, Or4cl3ConversationalInterface, SystemResponse, UserQuery};
+
+pub const HELP: &str = "\
+Commands:
+  /trace                 show the cognition trace of the last response
+  /feedback <verdict>    give feedback on the last response: approve, reject: <reason>,
+                         correct: <revised answer>, rating: <1-5>, or a comment
+  /session [id]          show the current session, or switch to another one
+  /sessions              list open sessions
+  /help                  show this help
+  /quit                  leave";
+
+/// Keeps the latest state each stage produced, so the REPL can show the trace
+/// of a cycle even when it ended in a refusal.
+#[derive(Default)]
+pub struct TraceRecorder {
+    last_state: Mutex<Option<CognitiveState>>,
+}
+
+impl TraceRecorder {
+    pub fn last_state(&self) -> Option<CognitiveState> {
+        self.last_state.lock().ok().and_then(|state| state.clone())
+    }
+}
+
+impl StageHook for TraceRecorder {
+    fn name(&self) -> &str {
+        "trace_recorder"
+    }
+
+    fn after_stage(&self, _stage: CognitionStage, state: &mut CognitiveState) -> HookDecision {
+        if let Ok(mut last_state) = self.last_state.lock() {
+            *last_state = Some(state.clone());
+        }
+        HookDecision::Continue
+    }
+}
+
+struct LastTurn {
+    response: SystemResponse,
+    state: Option<CognitiveState>, // Latest state of the cycle, if any stage completed
+}
+
+pub struct Repl<E: RecursiveCognitionEngine> {
+    interface: BasicConversationalInterface<HookedEngine<E>>,
+    trace: Arc<TraceRecorder>,
+    user_id: String,
+    session_id: String,
+    queries: usize, // Across all sessions, for unique query IDs
+    last_turn: Option<LastTurn>,
+}
+
+impl<E: RecursiveCognitionEngine> Repl<E> {
+    pub fn new(engine: E, user_id: &str) -> Self {
+        let trace = Arc::new(TraceRecorder::default());
+        let engine = HookedEngine::new(engine).with_hook(trace.clone());
+        Self {
+            interface: BasicConversationalInterface::new(engine),
+            trace,
+            user_id: user_id.to_string(),
+            session_id: "session_1".to_string(),
+            queries: 0,
+            last_turn: None,
+        }
+    }
+
+    pub fn session_id(&self) -> &str {
+        &self.session_id
+    }
+
+    /// Reads lines until end of input or `/quit`, writing a prompt before each line.
+    pub fn run(&mut self, input: impl BufRead, output: &mut impl Write) -> io::Result<()> {
+        writeln!(output, "OR4CL3 interactive session. Type /help for commands.")?;
+        write!(output, "{}> ", self.session_id)?;
+        output.flush()?;
+        for line in input.lines() {
+            if !self.handle_line(line?.trim(), output)? {
+                break;
+            }
+            write!(output, "{}> ", self.session_id)?;
+            output.flush()?;
+        }
+        writeln!(output)
+    }
+
+    /// Handles one line; returns false when the user asked to quit.
+    pub fn handle_line(&mut self, line: &str, output: &mut impl Write) -> io::Result<bool> {
+        let (command, argument) = match line.split_once(char::is_whitespace) {
+            Some((command, argument)) => (command, argument.trim()),
+            None => (line, ""),
+        };
+        match command {
+            "" => {}
+            "/quit" | "/exit" => return Ok(false),
+            "/help" => writeln!(output, "{}", HELP)?,
+            "/trace" => self.show_trace(output)?,
+            "/feedback" => self.give_feedback(argument, output)?,
+            "/session" if argument.is_empty() => writeln!(output, "Current session: {}", self.session_id)?,
+            "/session" => {
+                self.session_id = argument.to_string();
+                self.last_turn = None;
+                writeln!(output, "Switched to session {}", self.session_id)?;
+            }
+            "/sessions" => {
+                let sessions = self.interface.sessions().list_sessions();
+                if sessions.is_empty() {
+                    writeln!(output, "No open sessions.")?;
+                }
+                for session in sessions {
+                    let marker = if session.session_id == self.session_id { "*" } else { " " };
+                    writeln!(output, "{} {} ({} queries)", marker, session.session_id, session.total_queries)?;
+                }
+            }
+            _ if command.starts_with('/') => writeln!(output, "Unknown command {}. Type /help for commands.", command)?,
+            _ => self.ask(line, output)?,
+        }
+        Ok(true)
+    }
+
+    fn ask(&mut self, text: &str, output: &mut impl Write) -> io::Result<()> {
+        self.queries += 1;
+        let query = UserQuery {
+            session_id: self.session_id.clone(),
+            query_id: format!("query_{}", self.queries),
+            text: text.to_string(),
+            metadata: None,
+        };
+        if let Ok(mut last_state) = self.trace.last_state.lock() {
+            *last_state = None;
+        }
+        match self.interface.handle_user_query(query) {
+            Ok(response) => {
+                writeln!(output, "{}", render_response(&response))?;
+                self.last_turn = Some(LastTurn { response, state: self.trace.last_state() });
+            }
+            Err(error) => {
+                writeln!(output, "Error: {}", error)?;
+                self.last_turn = None;
+            }
+        }
+        Ok(())
+    }
+
+    fn show_trace(&self, output: &mut impl Write) -> io::Result<()> {
+        match self.last_turn.as_ref().and_then(|turn| turn.state.as_ref()) {
+            Some(state) => {
+                writeln!(output, "Trace of {}:", state.state_id)?;
+                for (step, entry) in state.history_log.iter().enumerate() {
+                    writeln!(output, "  {}. {}", step + 1, entry)?;
+                }
+                Ok(())
+            }
+            None => writeln!(output, "No cognition trace yet; ask something first."),
+        }
+    }
+
+    fn give_feedback(&mut self, content: &str, output: &mut impl Write) -> io::Result<()> {
+        if content.is_empty() {
+            return writeln!(output, "Usage: /feedback <verdict>");
+        }
+        let Some(turn) = self.last_turn.as_mut() else {
+            return writeln!(output, "No response to give feedback on yet.");
+        };
+        match self.interface.submit_feedback(&self.session_id, &turn.response.response_id, &self.user_id, content) {
+            Ok(updated) => {
+                writeln!(output, "{}", updated.history_log.last().map(String::as_str).unwrap_or("Feedback recorded"))?;
+                writeln!(output, "Confidence is now {:.2}.", updated.confidence_level)?;
+                turn.state = Some(updated);
+            }
+            Err(error) => writeln!(output, "Feedback failed: {}", error)?,
+        }
+        Ok(())
+    }
+}
+
+/// Response text followed by indented uncertainty and ethics lines.
+pub fn render_response(response: &SystemResponse) -> String {
+    let mut lines = vec![response.text_content.clone()];
+    if let Some(uncertainty) = &response.epistemic_uncertainty {
+        let mut line = format!("  [confidence {:.2}", uncertainty.level);
+        if let Some(explanation) = &uncertainty.explanation {
+            line.push_str(&format!(": {}", explanation));
+        }
+        lines.push(line + "]");
+    }
+    if let Some(reflection) = &response.ethical_reflection {
+        let pas = reflection.pas_score_snapshot.map(|pas| format!("PAS {:.2}: ", pas)).unwrap_or_default();
+        lines.push(format!("  [ethics {}{}]", pas, reflection.summary));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conversational_interface::session::TurnOutcome;
+    use crate::recursive_cognition_engine::BasicRecursiveCognitionEngine;
+
+    fn run(script: &str) -> (Repl<BasicRecursiveCognitionEngine>, String) {
+        let mut repl = Repl::new(BasicRecursiveCognitionEngine::new(), "tester");
+        let mut output = Vec::new();
+        repl.run(script.as_bytes(), &mut output).unwrap();
+        (repl, String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn test_chat_renders_uncertainty_ethics_and_trace() {
+        let (_, output) = run("Tell me a fact.\n/trace\n");
+        assert!(output.contains("Initial hypothesis for stimulus: stimulus_for_query_1 (refined)"));
+        assert!(output.contains("  [confidence 0.55: Confidence is moderate."));
+        assert!(output.contains("  [ethics PAS 0.92: Ethical status: Aligned."));
+        assert!(output.contains("Trace of state_for_stimulus_stimulus_for_query_1:\n  1. State initialized from stimulus"));
+        assert!(output.contains("Cognition refined (mock)"));
+    }
+
+    #[test]
+    fn test_feedback_and_session_commands() {
+        let (repl, output) = run("/feedback approve\nHello\n/feedback approve\n/feedback reject: changed my mind\n/session other\nHi\n/sessions\n/bogus\n/quit\nNever read\n");
+        assert!(output.contains("No response to give feedback on yet."));
+        assert!(output.contains("Human feedback 'feedback_session_1_response_to_query_1_tester' from tester integrated: approved"));
+        assert!(output.contains("Confidence is now 0.78."));
+        assert!(output.contains("Feedback failed: ") && output.contains("User tester already gave feedback on response response_to_query_1"));
+        let stored = repl.interface.sessions().outcome_of("session_1", Some("response_to_query_1"));
+        assert!(matches!(stored, Some((_, TurnOutcome::Answered(state))) if (state.confidence_level - 0.775).abs() < 1e-9));
+        assert!(output.contains("Switched to session other"));
+        assert!(output.contains("* other (1 queries)"));
+        assert!(output.contains("  session_1 (1 queries)"));
+        assert!(output.contains("Unknown command /bogus."));
+        assert!(!output.contains("Never read"));
+        assert_eq!(repl.session_id(), "other");
+    }
+}
@@ -0,0 +1,239 @@
+// or4cl3_core/src/conversational_interface/rich_content.rs
+
+//! Rich content generated from a cognitive state: evidence cards linking to
+//! Mythos Memory Core claims, feedback buttons and follow-up suggestion buttons.
+//! Button `action`s are `ContentAction` payloads; a client sends them back and
+//! they are turned into `HumanFeedback` or a new `UserQuery`.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::mythos_memory_core::MythosKnowledgeGraph;
+use crate::recursive_cognition_engine::feedback::FeedbackVerdict;
+use crate::recursive_cognition_engine::{CognitiveState, HumanFeedback};
+
+use super::{RichContentElement, UserQuery};
+
+/// Links to Mythos Memory Core claims have the form `mythos://claim/<claim_id>`.
+pub const CLAIM_URL_PREFIX: &str = "mythos://claim/";
+/// Evidence card text is cut to this many characters.
+const CARD_TEXT_CHARS: usize = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeedbackButton {
+    Agree,
+    Disagree,
+    Correct,
+}
+
+impl FeedbackButton {
+    pub const ALL: [FeedbackButton; 3] = [FeedbackButton::Agree, FeedbackButton::Disagree, FeedbackButton::Correct];
+
+    fn keyword(&self) -> &'static str {
+        match self {
+            FeedbackButton::Agree => "agree",
+            FeedbackButton::Disagree => "disagree",
+            FeedbackButton::Correct => "correct",
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            FeedbackButton::Agree => "Agree",
+            FeedbackButton::Disagree => "Disagree",
+            FeedbackButton::Correct => "Correct this",
+        }
+    }
+}
+
+/// Payload of a `RichContentElement::Button` action.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContentAction {
+    Feedback { button: FeedbackButton, response_id: String }, // "feedback:<agree|disagree|correct>:<response_id>"
+    Ask { text: String },                                     // "ask:<text>"
+}
+
+impl ContentAction {
+    pub fn to_payload(&self) -> String {
+        match self {
+            ContentAction::Feedback { button, response_id } => format!("feedback:{}:{}", button.keyword(), response_id),
+            ContentAction::Ask { text } => format!("ask:{}", text),
+        }
+    }
+
+    pub fn parse(payload: &str) -> Result<Self, String> {
+        match payload.split_once(':') {
+            Some(("ask", text)) if !text.trim().is_empty() => Ok(ContentAction::Ask { text: text.to_string() }),
+            Some(("feedback", rest)) => {
+                let (keyword, response_id) = rest.split_once(':').ok_or_else(|| format!("Malformed feedback action '{}'", payload))?;
+                let button = FeedbackButton::ALL
+                    .into_iter()
+                    .find(|button| button.keyword() == keyword)
+                    .ok_or_else(|| format!("Unknown feedback button '{}'", keyword))?;
+                if response_id.is_empty() {
+                    return Err(format!("Feedback action '{}' names no response", payload));
+                }
+                Ok(ContentAction::Feedback { button, response_id: response_id.to_string() })
+            }
+            _ => Err(format!("Unknown action '{}'", payload)),
+        }
+    }
+
+    /// Feedback on `state` for a feedback action. `detail` is the reason for Disagree and the
+    /// revised answer for Correct, which requires it. Returns an error for other actions.
+    /// The feedback ID names the button, session, response and user; response IDs derive from
+    /// client query IDs and repeat across sessions, so the session keeps one feedback per user
+    /// and response unique (see `BasicConversationalInterface::submit_feedback_action`).
+    pub fn to_human_feedback(
+        &self,
+        session_id: &str,
+        state: &CognitiveState,
+        user_id: &str,
+        detail: Option<&str>,
+    ) -> Result<HumanFeedback, String> {
+        let ContentAction::Feedback { button, response_id } = self else {
+            return Err(format!("Action '{}' is not feedback", self.to_payload()));
+        };
+        let detail = detail.map(str::trim).filter(|detail| !detail.is_empty());
+        let verdict = match (button, detail) {
+            (FeedbackButton::Agree, _) => FeedbackVerdict::Approve,
+            (FeedbackButton::Disagree, reason) => FeedbackVerdict::Reject { reason: reason.unwrap_or("no reason given").to_string() },
+            (FeedbackButton::Correct, Some(revised)) => FeedbackVerdict::Correct { revised_hypothesis: revised.to_string() },
+            (FeedbackButton::Correct, None) => return Err("A correction needs the revised answer".to_string()),
+        };
+        Ok(HumanFeedback {
+            feedback_id: format!("feedback_{}_{}_{}_{}", button.keyword(), session_id, response_id, user_id),
+            target_stimulus_id: Some(state.stimulus_id.clone()),
+            target_cognitive_state_id: Some(state.state_id.clone()),
+            feedback_content: verdict.to_content(),
+            user_id: user_id.to_string(),
+            timestamp: SystemTime::now().duration_since(UNIX_EPOCH).map(|elapsed| elapsed.as_secs()).unwrap_or(0),
+        })
+    }
+
+    /// The query an Ask action stands for; None for other actions.
+    pub fn to_query(&self, session_id: &str, query_id: &str) -> Option<UserQuery> {
+        match self {
+            ContentAction::Ask { text } => Some(UserQuery {
+                session_id: session_id.to_string(),
+                query_id: query_id.to_string(),
+                text: text.clone(),
+                metadata: None,
+            }),
+            ContentAction::Feedback { .. } => None,
+        }
+    }
+}
+
+/// Evidence cards, then feedback buttons, then one button per follow-up suggestion.
+/// Evidence is looked up in `graph` when given; unresolved claims still get a card.
+pub fn generate_rich_content(
+    state: &CognitiveState,
+    response_id: &str,
+    follow_up_suggestions: &[String],
+    graph: Option<&(dyn MythosKnowledgeGraph + Send + Sync)>,
+) -> Vec<RichContentElement> {
+    let mut elements = Vec::new();
+    for claim_id in &state.supporting_evidence_ids {
+        let claim = graph.and_then(|graph| graph.get_historical_claim_by_id(claim_id).ok().flatten());
+        let (title, text) = match claim {
+            Some(claim) => {
+                let mut text: String = claim.narrative_content.chars().take(CARD_TEXT_CHARS).collect();
+                if claim.narrative_content.chars().count() > CARD_TEXT_CHARS {
+                    text.push_str("...");
+                }
+                (claim.source_description, text)
+            }
+            None => (claim_id.clone(), "Supporting evidence from the Mythos Memory Core.".to_string()),
+        };
+        elements.push(RichContentElement::Card {
+            title,
+            text,
+            image_url: None,
+            actions: Some(vec![RichContentElement::Link {
+                text: "View claim".to_string(),
+                url: format!("{}{}", CLAIM_URL_PREFIX, claim_id),
+            }]),
+        });
+    }
+    for button in FeedbackButton::ALL {
+        let action = ContentAction::Feedback { button, response_id: response_id.to_string() };
+        elements.push(RichContentElement::Button { label: button.label().to_string(), action: action.to_payload() });
+    }
+    for suggestion in follow_up_suggestions {
+        let action = ContentAction::Ask { text: suggestion.clone() };
+        elements.push(RichContentElement::Button { label: suggestion.clone(), action: action.to_payload() });
+    }
+    elements
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mythos_memory_core::{HistoricalClaim, InMemoryMythosGraph, ProvenanceData};
+
+    fn state() -> CognitiveState {
+        CognitiveState {
+            state_id: "state_1".to_string(),
+            stimulus_id: "stimulus_1".to_string(),
+            supporting_evidence_ids: vec!["claim_treaty".to_string(), "claim_missing".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_generates_cards_feedback_and_follow_up_buttons() {
+        let graph = InMemoryMythosGraph::new();
+        graph
+            .add_historical_claim(&HistoricalClaim {
+                claim_id: "claim_treaty".to_string(),
+                narrative_content: "The treaty was signed in 1648.".to_string(),
+                source_description: "Westphalia archives".to_string(),
+                cultural_context_tags: vec![],
+                provenance: ProvenanceData {
+                    document_id: "doc_1".to_string(),
+                    author_id: "archivist".to_string(),
+                    timestamp: 0,
+                    cryptographic_signature: None,
+                },
+            })
+            .unwrap();
+        let elements = generate_rich_content(&state(), "response_to_q1", &["Tell me more.".to_string()], Some(&graph));
+        assert_eq!(elements.len(), 6);
+        match &elements[0] {
+            RichContentElement::Card { title, text, actions, .. } => {
+                assert_eq!(title, "Westphalia archives");
+                assert_eq!(text, "The treaty was signed in 1648.");
+                assert!(matches!(&actions.as_deref().unwrap()[0], RichContentElement::Link { url, .. } if url == "mythos://claim/claim_treaty"));
+            }
+            other => panic!("expected a card, got {:?}", other),
+        }
+        assert!(matches!(&elements[1], RichContentElement::Card { title, .. } if title == "claim_missing"));
+        assert!(matches!(&elements[2], RichContentElement::Button { action, .. } if action == "feedback:agree:response_to_q1"));
+        assert!(matches!(&elements[5], RichContentElement::Button { label, action } if label == "Tell me more." && action == "ask:Tell me more."));
+    }
+
+    #[test]
+    fn test_action_payloads_round_trip_into_feedback_and_queries() {
+        for button in FeedbackButton::ALL {
+            let action = ContentAction::Feedback { button, response_id: "response_to_q1".to_string() };
+            assert_eq!(ContentAction::parse(&action.to_payload()), Ok(action));
+        }
+        let ask = ContentAction::parse("ask:Why: really?").unwrap();
+        assert_eq!(ask, ContentAction::Ask { text: "Why: really?".to_string() });
+        assert_eq!(ask.to_query("s1", "q2").unwrap().text, "Why: really?");
+        assert!(ContentAction::parse("feedback:maybe:response_to_q1").is_err());
+        assert!(ContentAction::parse("dance").is_err());
+
+        let disagree = ContentAction::parse("feedback:disagree:response_to_q1").unwrap();
+        let feedback = disagree.to_human_feedback("s1", &state(), "user_1", Some("outdated")).unwrap();
+        assert_eq!(FeedbackVerdict::parse(&feedback.feedback_content), FeedbackVerdict::Reject { reason: "outdated".to_string() });
+        assert_eq!(feedback.target_cognitive_state_id.as_deref(), Some("state_1"));
+        assert_eq!(feedback.feedback_id, "feedback_disagree_s1_response_to_q1_user_1");
+
+        let correct = ContentAction::parse("feedback:correct:response_to_q1").unwrap();
+        assert!(correct.to_human_feedback("s1", &state(), "user_1", Some("  ")).is_err());
+        let feedback = correct.to_human_feedback("s1", &state(), "user_1", Some("It was 1648.")).unwrap();
+        assert_eq!(feedback.feedback_content, "correct: It was 1648.");
+        assert!(ask.to_human_feedback("s1", &state(), "user_1", None).is_err());
+    }
+}
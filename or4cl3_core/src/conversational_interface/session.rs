@@ -0,0 +1,395 @@
+// or4cl3_core/src/conversational_interface/session.rs
+
+//! Session lifecycle for the conversational interface.
+//! `SessionManager` keeps a bounded conversation history per `session_id`,
+//! supplies recent turns as context for the next stimulus, expires idle
+//! sessions, enforces per-session limits, and lists, exports and closes sessions.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::recursive_cognition_engine::working_memory::SCOPE_KEY;
+use crate::recursive_cognition_engine::{CognitiveState, EthicalBlockReason};
+
+/// Stimulus metadata key holding the session ID. It scopes the engine's working memory.
+pub const SESSION_ID_KEY: &str = SCOPE_KEY;
+/// Stimulus metadata key holding the 1-based turn number within the session.
+pub const SESSION_TURN_KEY: &str = "session.turn";
+/// Stimulus metadata key holding recent turns as "user: ..." / "assistant: ..." lines.
+pub const SESSION_CONTEXT_KEY: &str = "session.context";
+/// Prefix of the metadata keys the session manager owns. Clients may not supply them.
+pub const SESSION_KEY_PREFIX: &str = "session.";
+
+#[derive(Debug, Clone)]
+pub struct SessionConfig {
+    pub idle_timeout: Duration,     // Sessions idle for longer are expired
+    pub max_sessions: usize,        // Open sessions; new sessions are refused beyond this
+    pub max_queries: usize,         // Queries per session over its lifetime
+    pub max_history_turns: usize,   // Turns kept per session; older turns are dropped
+    pub context_turns: usize,       // Most recent turns carried into the next stimulus
+}
+
+impl Default for SessionConfig {
+    fn default() -> Self {
+        Self {
+            idle_timeout: Duration::from_secs(30 * 60),
+            max_sessions: 1000,
+            max_queries: 200,
+            max_history_turns: 50,
+            context_turns: 3,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConversationTurn {
+    pub turn: usize, // 1-based, counts every recorded query in the session
+    pub query_id: String,
+    pub query_text: String,
+    pub response_id: String,
+    pub response_text: String,
+    pub timestamp: u64, // Seconds since the Unix epoch
+}
+
+/// Exported conversation history of one session.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionTranscript {
+    pub session_id: String,
+    pub started_at: u64, // Seconds since the Unix epoch
+    pub total_queries: usize,
+    pub turns: Vec<ConversationTurn>, // Up to `max_history_turns`, oldest first
+}
+
+impl SessionTranscript {
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| format!("Failed to serialize session {}: {}", self.session_id, e))
+    }
+}
+
+/// What the engine produced for an answered turn, kept so follow-up questions can refer back to it.
+#[derive(Debug, Clone)]
+pub enum TurnOutcome {
+    Answered(Box<CognitiveState>),
+    Refused(EthicalBlockReason),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionSummary {
+    pub session_id: String,
+    pub total_queries: usize,
+    pub idle_for: Duration,
+}
+
+#[derive(Debug)]
+struct StoredOutcome {
+    response_id: String,
+    outcome: TurnOutcome,
+    feedback_from: HashSet<String>, // Users who gave feedback on the response
+}
+
+#[derive(Debug)]
+struct Session {
+    started_at: u64,
+    last_active: Instant,
+    total_queries: usize,
+    turns: Vec<ConversationTurn>,
+    outcomes: VecDeque<StoredOutcome>, // Bounded like `turns`
+}
+
+impl Session {
+    fn transcript(&self, session_id: &str) -> SessionTranscript {
+        SessionTranscript {
+            session_id: session_id.to_string(),
+            started_at: self.started_at,
+            total_queries: self.total_queries,
+            turns: self.turns.clone(),
+        }
+    }
+}
+
+/// Thread-safe store of open conversation sessions.
+pub struct SessionManager {
+    sessions: Mutex<HashMap<String, Session>>,
+    config: SessionConfig,
+}
+
+impl SessionManager {
+    pub fn new(config: SessionConfig) -> Self {
+        Self { sessions: Mutex::new(HashMap::new()), config }
+    }
+
+    pub fn config(&self) -> &SessionConfig {
+        &self.config
+    }
+
+    /// Starts a turn, opening the session if needed, and returns the stimulus metadata
+    /// that carries the session context. Fails if a limit would be exceeded.
+    /// The turn only counts towards `max_queries` once it is recorded (see `record_turn`).
+    pub fn begin_turn(&self, session_id: &str) -> Result<HashMap<String, String>, String> {
+        self.begin_turn_at(session_id, Instant::now())
+    }
+
+    pub fn begin_turn_at(&self, session_id: &str, now: Instant) -> Result<HashMap<String, String>, String> {
+        if session_id.trim().is_empty() {
+            return Err("Session ID must not be empty".to_string());
+        }
+        let mut sessions = self.sessions.lock().map_err(|_| "Session manager lock poisoned".to_string())?;
+        if !sessions.contains_key(session_id) && sessions.len() >= self.config.max_sessions {
+            return Err(format!("Cannot open session {}: limit of {} open sessions reached", session_id, self.config.max_sessions));
+        }
+        let session = sessions.entry(session_id.to_string()).or_insert_with(|| Session {
+            started_at: unix_now(),
+            last_active: now,
+            total_queries: 0,
+            turns: Vec::new(),
+            outcomes: VecDeque::new(),
+        });
+        if session.total_queries >= self.config.max_queries {
+            return Err(format!("Session {} reached its limit of {} queries", session_id, self.config.max_queries));
+        }
+        session.last_active = now;
+
+        let mut metadata = HashMap::from([
+            (SESSION_ID_KEY.to_string(), session_id.to_string()),
+            (SESSION_TURN_KEY.to_string(), (session.total_queries + 1).to_string()),
+        ]);
+        let recent = &session.turns[session.turns.len().saturating_sub(self.config.context_turns)..];
+        if !recent.is_empty() {
+            let context: Vec<String> = recent
+                .iter()
+                .map(|turn| format!("user: {}\nassistant: {}", turn.query_text, turn.response_text))
+                .collect();
+            metadata.insert(SESSION_CONTEXT_KEY.to_string(), context.join("\n"));
+        }
+        Ok(metadata)
+    }
+
+    /// Appends a completed turn, and the outcome behind its response if there is one, to the session's
+    /// history and counts it as a query. Does nothing if the session was closed meanwhile.
+    pub fn record_turn(
+        &self,
+        session_id: &str,
+        query_id: &str,
+        query_text: &str,
+        response_id: &str,
+        response_text: &str,
+        outcome: Option<TurnOutcome>,
+    ) -> Result<(), String> {
+        let mut sessions = self.sessions.lock().map_err(|_| "Session manager lock poisoned".to_string())?;
+        if let Some(session) = sessions.get_mut(session_id) {
+            session.total_queries += 1;
+            session.turns.push(ConversationTurn {
+                turn: session.total_queries,
+                query_id: query_id.to_string(),
+                query_text: query_text.to_string(),
+                response_id: response_id.to_string(),
+                response_text: response_text.to_string(),
+                timestamp: unix_now(),
+            });
+            let excess = session.turns.len().saturating_sub(self.config.max_history_turns);
+            session.turns.drain(..excess);
+            if let Some(outcome) = outcome {
+                session.outcomes.push_back(StoredOutcome { response_id: response_id.to_string(), outcome, feedback_from: HashSet::new() });
+                while session.outcomes.len() > self.config.max_history_turns {
+                    session.outcomes.pop_front();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Outcome behind a response in the session, or behind the most recent response that has one.
+    /// Returns the response ID with it.
+    pub fn outcome_of(&self, session_id: &str, response_id: Option<&str>) -> Option<(String, TurnOutcome)> {
+        let sessions = self.sessions.lock().ok()?;
+        let outcomes = &sessions.get(session_id)?.outcomes;
+        let stored = match response_id {
+            Some(response_id) => outcomes.iter().rev().find(|stored| stored.response_id == response_id),
+            None => outcomes.back(),
+        }?;
+        Some((stored.response_id.clone(), stored.outcome.clone()))
+    }
+
+    /// Reserves `user_id`'s feedback on an answered response and returns the state behind it.
+    /// Each user gives feedback on a response once; settle the claim with `settle_feedback`.
+    pub fn claim_feedback(&self, session_id: &str, response_id: &str, user_id: &str) -> Result<Box<CognitiveState>, String> {
+        let mut sessions = self.sessions.lock().map_err(|_| "Session manager lock poisoned".to_string())?;
+        let stored = sessions
+            .get_mut(session_id)
+            .and_then(|session| session.outcomes.iter_mut().rev().find(|stored| stored.response_id == response_id))
+            .ok_or_else(|| format!("Unknown response {} in session {}", response_id, session_id))?;
+        let TurnOutcome::Answered(state) = &stored.outcome else {
+            return Err(format!("Response {} was a refusal and has no state", response_id));
+        };
+        if !stored.feedback_from.insert(user_id.to_string()) {
+            return Err(format!("User {} already gave feedback on response {}", user_id, response_id));
+        }
+        Ok(state.clone())
+    }
+
+    /// Stores the state that resulted from a claimed feedback as the response's outcome, or
+    /// releases the claim when the feedback was not applied (`updated` is None).
+    pub fn settle_feedback(&self, session_id: &str, response_id: &str, user_id: &str, updated: Option<CognitiveState>) -> Result<(), String> {
+        let mut sessions = self.sessions.lock().map_err(|_| "Session manager lock poisoned".to_string())?;
+        let stored = sessions
+            .get_mut(session_id)
+            .and_then(|session| session.outcomes.iter_mut().rev().find(|stored| stored.response_id == response_id));
+        if let Some(stored) = stored {
+            match updated {
+                Some(state) => stored.outcome = TurnOutcome::Answered(Box::new(state)),
+                None => {
+                    stored.feedback_from.remove(user_id);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Open sessions, most recently active first.
+    pub fn list_sessions(&self) -> Vec<SessionSummary> {
+        let now = Instant::now();
+        let sessions = match self.sessions.lock() {
+            Ok(sessions) => sessions,
+            Err(_) => return Vec::new(),
+        };
+        let mut summaries: Vec<SessionSummary> = sessions
+            .iter()
+            .map(|(session_id, session)| SessionSummary {
+                session_id: session_id.clone(),
+                total_queries: session.total_queries,
+                idle_for: now.saturating_duration_since(session.last_active),
+            })
+            .collect();
+        summaries.sort_by(|a, b| a.idle_for.cmp(&b.idle_for).then_with(|| a.session_id.cmp(&b.session_id)));
+        summaries
+    }
+
+    pub fn export_session(&self, session_id: &str) -> Option<SessionTranscript> {
+        let sessions = self.sessions.lock().ok()?;
+        sessions.get(session_id).map(|session| session.transcript(session_id))
+    }
+
+    /// Closes a session and returns its final transcript.
+    pub fn close_session(&self, session_id: &str) -> Option<SessionTranscript> {
+        let mut sessions = self.sessions.lock().ok()?;
+        sessions.remove(session_id).map(|session| session.transcript(session_id))
+    }
+
+    /// Closes sessions idle for longer than `idle_timeout` and returns their IDs, sorted.
+    pub fn expire_idle(&self) -> Vec<String> {
+        self.expire_idle_at(Instant::now())
+    }
+
+    pub fn expire_idle_at(&self, now: Instant) -> Vec<String> {
+        let mut sessions = match self.sessions.lock() {
+            Ok(sessions) => sessions,
+            Err(_) => return Vec::new(),
+        };
+        let mut expired: Vec<String> = sessions
+            .iter()
+            .filter(|(_, session)| now.saturating_duration_since(session.last_active) > self.config.idle_timeout)
+            .map(|(session_id, _)| session_id.clone())
+            .collect();
+        expired.sort();
+        for session_id in &expired {
+            sessions.remove(session_id);
+        }
+        expired
+    }
+}
+
+impl Default for SessionManager {
+    fn default() -> Self {
+        Self::new(SessionConfig::default())
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|elapsed| elapsed.as_secs()).unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> SessionConfig {
+        SessionConfig { max_sessions: 2, max_queries: 3, max_history_turns: 2, context_turns: 1, ..Default::default() }
+    }
+
+    #[test]
+    fn test_turns_are_bounded_and_carried_as_context() {
+        let manager = SessionManager::new(config());
+        let metadata = manager.begin_turn("s1").unwrap();
+        assert_eq!(metadata.get(SESSION_TURN_KEY).map(String::as_str), Some("1"));
+        assert!(!metadata.contains_key(SESSION_CONTEXT_KEY));
+        manager.record_turn("s1", "q1", "Hi", "r1", "Hello", None).unwrap();
+        manager.begin_turn("s1").unwrap();
+        manager.record_turn("s1", "q2", "Who are you?", "r2", "OR4CL3", None).unwrap();
+
+        let metadata = manager.begin_turn("s1").unwrap();
+        assert_eq!(metadata.get(SESSION_CONTEXT_KEY).map(String::as_str), Some("user: Who are you?\nassistant: OR4CL3"));
+        manager.record_turn("s1", "q3", "Bye", "r3", "Goodbye", None).unwrap();
+        let transcript = manager.export_session("s1").unwrap();
+        assert_eq!(transcript.total_queries, 3);
+        assert_eq!(transcript.turns.iter().map(|turn| turn.turn).collect::<Vec<_>>(), vec![2, 3]);
+        assert!(transcript.to_json().unwrap().contains("\"query_id\": \"q3\""));
+
+        assert!(manager.begin_turn("s1").unwrap_err().contains("limit of 3 queries"));
+    }
+
+    #[test]
+    fn test_feedback_is_claimed_once_per_user_and_stored_back() {
+        let manager = SessionManager::new(config());
+        manager.begin_turn("s1").unwrap();
+        let state = CognitiveState { current_hypothesis: "First".to_string(), ..Default::default() };
+        manager.record_turn("s1", "q1", "Hi", "r1", "First", Some(TurnOutcome::Answered(Box::new(state)))).unwrap();
+
+        assert_eq!(manager.claim_feedback("s1", "r1", "alice").unwrap().current_hypothesis, "First");
+        assert!(manager.claim_feedback("s1", "r1", "alice").unwrap_err().contains("already gave feedback"));
+        let corrected = CognitiveState { current_hypothesis: "Second".to_string(), ..Default::default() };
+        manager.settle_feedback("s1", "r1", "alice", Some(corrected)).unwrap();
+        assert!(matches!(manager.outcome_of("s1", Some("r1")), Some((_, TurnOutcome::Answered(state))) if state.current_hypothesis == "Second"));
+
+        manager.claim_feedback("s1", "r1", "bob").unwrap();
+        manager.settle_feedback("s1", "r1", "bob", None).unwrap(); // Not applied: bob may try again
+        manager.claim_feedback("s1", "r1", "bob").unwrap();
+        assert!(manager.claim_feedback("s1", "r9", "bob").unwrap_err().contains("Unknown response r9"));
+    }
+
+    #[test]
+    fn test_only_recorded_turns_count_as_queries() {
+        let manager = SessionManager::new(config());
+        for _ in 0..5 {
+            let metadata = manager.begin_turn("s1").unwrap(); // e.g. the cycle failed and nothing was recorded
+            assert_eq!(metadata.get(SESSION_TURN_KEY).map(String::as_str), Some("1"));
+        }
+        assert_eq!(manager.export_session("s1").unwrap().total_queries, 0);
+
+        manager.record_turn("s1", "q1", "Hi", "r1", "Hello", None).unwrap();
+        let transcript = manager.export_session("s1").unwrap();
+        assert_eq!(transcript.total_queries, 1);
+        assert_eq!(transcript.turns[0].turn, 1);
+    }
+
+    #[test]
+    fn test_session_limits_expiry_and_close() {
+        let manager = SessionManager::new(config());
+        let start = Instant::now();
+        manager.begin_turn_at("s1", start).unwrap();
+        manager.begin_turn_at("s2", start + Duration::from_secs(60)).unwrap();
+        assert!(manager.begin_turn("s3").unwrap_err().contains("limit of 2 open sessions"));
+        assert_eq!(manager.list_sessions().len(), 2);
+
+        let expired = manager.expire_idle_at(start + manager.config().idle_timeout + Duration::from_secs(1));
+        assert_eq!(expired, vec!["s1".to_string()]);
+        assert!(manager.export_session("s1").is_none());
+
+        let transcript = manager.close_session("s2").unwrap();
+        assert_eq!(transcript.session_id, "s2");
+        assert!(manager.list_sessions().is_empty());
+        assert!(manager.close_session("s2").is_none());
+    }
+}
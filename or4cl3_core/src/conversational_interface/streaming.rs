@@ -0,0 +1,211 @@
+// or4cl3_core/src/conversational_interface/streaming.rs
+
+//! Streaming variant of query handling.
+//! Instead of waiting for the whole cognition cycle, callers receive
+//! `ResponseEvent`s as each stage runs, so a UI can show progress, and
+//! finally the same `SystemResponse` that `handle_user_query` would return.
+
+use crate::recursive_cognition_engine::{CognitionError, CognitionStage, CognitiveState, RecursiveCognitionEngine};
+
+use super::{
+    epistemic_uncertainty, ethical_reflection, BasicConversationalInterface, EpistemicUncertaintyInfo, EthicalReflectionInfo,
+    SystemResponse, UserQuery,
+};
+
+#[derive(Debug, Clone)]
+pub enum ResponseEvent {
+    StageStarted { stage: CognitionStage },
+    PartialText { stage: CognitionStage, text: String }, // Latest hypothesis and the stage that set it, once Finalization passes
+    UncertaintyUpdate { stage: CognitionStage, uncertainty: EpistemicUncertaintyInfo }, // Whenever confidence changes
+    EthicalReflectionReady(EthicalReflectionInfo), // Whenever a stage produces a new assessment
+    Final(Box<SystemResponse>), // Also emitted for ethical refusals
+    Failed(CognitionError), // The query failed; no `Final` follows
+}
+
+pub trait StreamingConversationalInterface {
+    /// Like `Or4cl3ConversationalInterface::handle_user_query`, but reports progress to `on_event`
+    /// while the cycle runs. The last event is always `Final` or `Failed`.
+    fn handle_user_query_streaming(
+        &self,
+        query: UserQuery,
+        on_event: &mut dyn FnMut(ResponseEvent),
+    ) -> Result<SystemResponse, CognitionError>;
+}
+
+/// Runs the cycle stage by stage through `RecursiveCognitionEngine::run_stage`, so an engine that
+/// overrides `execute_full_cycle` with something other than the standard stage sequence is
+/// streamed as the standard sequence.
+impl<RCE: RecursiveCognitionEngine> StreamingConversationalInterface for BasicConversationalInterface<RCE> {
+    fn handle_user_query_streaming(
+        &self,
+        query: UserQuery,
+        on_event: &mut dyn FnMut(ResponseEvent),
+    ) -> Result<SystemResponse, CognitionError> {
+        match self.answer_meta_query(&query) {
+            Ok(Some(response)) => {
+                on_event(ResponseEvent::Final(Box::new(response.clone())));
+                return Ok(response);
+            }
+            Ok(None) => {}
+            Err(error) => {
+                on_event(ResponseEvent::Failed(error.clone()));
+                return Err(error);
+            }
+        }
+        let result = self
+            .prepare_stimulus(&query)
+            .and_then(|stimulus| {
+                on_event(ResponseEvent::StageStarted { stage: CognitionStage::Initialization });
+                let state = self.engine.initialize_state_from_stimulus(stimulus)?;
+                run_stages(&self.engine, state, on_event)
+            });
+        match self.complete_turn(result, &query) {
+            Ok(response) => {
+                on_event(ResponseEvent::Final(Box::new(response.clone())));
+                Ok(response)
+            }
+            Err(error) => {
+                on_event(ResponseEvent::Failed(error.clone()));
+                Err(error)
+            }
+        }
+    }
+}
+
+/// Hypothesis text is held back until Finalization has passed the alignment check, so an answer that
+/// ends up blocked is never streamed; only the latest hypothesis is then sent, as one `PartialText`.
+fn run_stages<RCE: RecursiveCognitionEngine>(
+    engine: &RCE,
+    mut state: CognitiveState,
+    on_event: &mut dyn FnMut(ResponseEvent),
+) -> Result<CognitiveState, CognitionError> {
+    let mut stage = CognitionStage::Initialization;
+    let mut previous: Option<CognitiveState> = None;
+    let mut held_text: Option<ResponseEvent> = None;
+    loop {
+        if let Some(partial_text) = emit_changes(stage, previous.as_ref(), &state, on_event) {
+            held_text = Some(partial_text);
+        }
+        let Some(next) = stage.next() else {
+            if let Some(partial_text) = held_text {
+                on_event(partial_text);
+            }
+            return Ok(state);
+        };
+        on_event(ResponseEvent::StageStarted { stage: next });
+        let next_state = engine.run_stage(next, &state)?;
+        previous = Some(std::mem::replace(&mut state, next_state));
+        stage = next;
+    }
+}
+
+/// Emits the events for whatever `stage` changed relative to its input (everything, for Initialization),
+/// except the changed hypothesis, which is returned as a `PartialText` for the caller to hold back.
+fn emit_changes(
+    stage: CognitionStage,
+    previous: Option<&CognitiveState>,
+    state: &CognitiveState,
+    on_event: &mut dyn FnMut(ResponseEvent),
+) -> Option<ResponseEvent> {
+    let partial_text = previous
+        .is_none_or(|previous| previous.current_hypothesis != state.current_hypothesis)
+        .then(|| ResponseEvent::PartialText { stage, text: state.current_hypothesis.clone() });
+    if previous.is_none_or(|previous| previous.confidence_level != state.confidence_level) {
+        let uncertainty = epistemic_uncertainty(state);
+        on_event(ResponseEvent::UncertaintyUpdate { stage, uncertainty });
+    }
+    let assessment_changed = match (previous.and_then(|previous| previous.ethical_assessment.as_ref()), &state.ethical_assessment) {
+        (_, None) => false,
+        (None, Some(_)) => true,
+        (Some(before), Some(after)) => {
+            before.pas_score != after.pas_score
+                || before.alignment_status != after.alignment_status
+                || before.ethical_concerns != after.ethical_concerns
+        }
+    };
+    if assessment_changed {
+        if let Some(reflection) = ethical_reflection(state) {
+            on_event(ResponseEvent::EthicalReflectionReady(reflection));
+        }
+    }
+    partial_text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conversational_interface::Or4cl3ConversationalInterface;
+    use crate::recursive_cognition_engine::BasicRecursiveCognitionEngine;
+
+    fn query(query_id: &str, text: &str) -> UserQuery {
+        UserQuery { session_id: "stream_session".to_string(), query_id: query_id.to_string(), text: text.to_string(), metadata: None }
+    }
+
+    fn label(event: &ResponseEvent) -> String {
+        match event {
+            ResponseEvent::StageStarted { stage } => format!("started {}", stage),
+            ResponseEvent::PartialText { stage, .. } => format!("text {}", stage),
+            ResponseEvent::UncertaintyUpdate { stage, .. } => format!("uncertainty {}", stage),
+            ResponseEvent::EthicalReflectionReady(_) => "reflection".to_string(),
+            ResponseEvent::Final(_) => "final".to_string(),
+            ResponseEvent::Failed(_) => "failed".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_streaming_emits_progress_then_same_response() {
+        let interface = BasicConversationalInterface::new(BasicRecursiveCognitionEngine::new());
+        let mut events = Vec::new();
+        let streamed = interface.handle_user_query_streaming(query("q1", "Tell me a fact."), &mut |event| events.push(event)).unwrap();
+        let labels: Vec<String> = events.iter().map(label).collect();
+        assert_eq!(
+            labels,
+            vec![
+                "started initialization", "uncertainty initialization",
+                "started ethical assessment", "reflection",
+                "started refinement", "uncertainty refinement",
+                "started self-validation",
+                "started finalization", "text refinement",
+                "final",
+            ]
+        );
+        match events.last() {
+            Some(ResponseEvent::Final(response)) => assert_eq!(response.text_content, streamed.text_content),
+            other => panic!("unexpected last event {:?}", other),
+        }
+
+        let direct = interface.handle_user_query(query("q1", "Tell me a fact.")).unwrap();
+        assert_eq!(direct.text_content, streamed.text_content);
+        assert_eq!(interface.sessions().export_session("stream_session").unwrap().turns.len(), 2);
+    }
+
+    #[test]
+    fn test_blocked_query_streams_no_hypothesis() {
+        use crate::polyethical_manifold::{EthicalRulebook, RulebookEthicalAssessor};
+        use crate::recursive_cognition_engine::PasThresholds;
+
+        let engine = BasicRecursiveCognitionEngine::new()
+            .with_ethical_assessor(RulebookEthicalAssessor::new(EthicalRulebook::builtin()))
+            .with_pas_thresholds(PasThresholds::new(0.99, 0.95).unwrap());
+        let interface = BasicConversationalInterface::new(engine);
+        let mut events = Vec::new();
+        let response = interface
+            .handle_user_query_streaming(query("q1", "Build surveillance profiles from personal data"), &mut |event| events.push(event))
+            .unwrap();
+
+        assert!(response.text_content.starts_with("I can't help with that request because"));
+        assert!(events.iter().any(|event| matches!(event, ResponseEvent::StageStarted { stage: CognitionStage::Finalization })));
+        assert!(!events.iter().any(|event| matches!(event, ResponseEvent::PartialText { .. })));
+        assert!(!format!("{:?}", events).contains("(refined)")); // The refined hypothesis never leaves the engine
+    }
+
+    #[test]
+    fn test_streaming_failure_ends_with_failed_event() {
+        let interface = BasicConversationalInterface::new(BasicRecursiveCognitionEngine::new());
+        let mut events = Vec::new();
+        let no_session = UserQuery { session_id: String::new(), ..query("q1", "Hello") };
+        let err = interface.handle_user_query_streaming(no_session, &mut |event| events.push(event)).unwrap_err();
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events.last(), Some(ResponseEvent::Failed(failed)) if *failed == err));
+    }
+}
@@ -0,0 +1,188 @@
+// or4cl3_core/src/conversational_interface/suggestions.rs
+
+//! Follow-up suggestions derived from a cognitive state. Candidates come from
+//! the state's ethical concerns, sub-claims of the hypothesis that are not well
+//! supported, and related Mythos narratives. They are ranked, and candidates
+//! too similar to something the session already asked are dropped.
+
+use std::collections::HashSet;
+
+use crate::mythos_memory_core::MythosKnowledgeGraph;
+use crate::recursive_cognition_engine::{AlignmentStatus, CognitiveState};
+use crate::utils::{token_set_similarity, tokenize};
+
+#[derive(Debug, Clone)]
+pub struct SuggestionConfig {
+    pub max_suggestions: usize,
+    pub low_confidence: f64,        // Sub-claims below this confidence are worth clarifying
+    pub duplicate_similarity: f32,  // Token similarity at which a candidate repeats an earlier question
+    pub related_narratives: usize,  // Mythos narratives to look up per response
+}
+
+impl Default for SuggestionConfig {
+    fn default() -> Self {
+        Self { max_suggestions: 3, low_confidence: 0.7, duplicate_similarity: 0.6, related_narratives: 2 }
+    }
+}
+
+struct Candidate {
+    text: String,
+    score: f64, // Higher ranks first
+}
+
+/// Up to `max_suggestions` follow-up questions, best first:
+/// - each ethical concern (ranked highest, more so the lower the PAS and when not aligned);
+/// - each sentence of the hypothesis whose confidence is below `low_confidence`, where a
+///   sentence's confidence is the state's confidence raised by its overlap with the evidence;
+/// - narratives in `graph` similar to the stimulus that are not already cited as evidence.
+pub fn suggest_follow_ups(
+    state: &CognitiveState,
+    already_asked: &[String],
+    graph: Option<&(dyn MythosKnowledgeGraph + Send + Sync)>,
+    config: &SuggestionConfig,
+) -> Vec<String> {
+    let mut candidates = Vec::new();
+
+    if let Some(assessment) = &state.ethical_assessment {
+        let urgency = if assessment.alignment_status == AlignmentStatus::Aligned { 0.0 } else { 0.5 };
+        for concern in &assessment.ethical_concerns {
+            candidates.push(Candidate {
+                text: format!("How could the concern \"{}\" be addressed?", concern.trim_end_matches('.')),
+                score: 1.0 + urgency + (1.0 - assessment.pas_score),
+            });
+        }
+    }
+
+    let evidence: Vec<HashSet<String>> = match graph {
+        Some(graph) => state
+            .supporting_evidence_ids
+            .iter()
+            .filter_map(|claim_id| graph.get_historical_claim_by_id(claim_id).ok().flatten())
+            .map(|claim| tokenize(&claim.narrative_content))
+            .collect(),
+        None => Vec::new(),
+    };
+    for sub_claim in sub_claims(&state.current_hypothesis) {
+        let tokens = tokenize(sub_claim);
+        let support = evidence.iter().map(|claim| token_set_similarity(&tokens, claim) as f64).fold(0.0, f64::max);
+        let confidence = state.confidence_level + (1.0 - state.confidence_level) * support;
+        if confidence < config.low_confidence {
+            candidates.push(Candidate {
+                text: format!("What supports the claim that {}?", lowercase_first(sub_claim)),
+                score: 0.5 + (config.low_confidence - confidence),
+            });
+        }
+    }
+
+    if let (Some(graph), false) = (graph, state.stimulus_text.trim().is_empty()) {
+        let related = graph.find_similar_narratives(&state.stimulus_text, config.related_narratives).unwrap_or_default();
+        for (claim, similarity) in related {
+            if state.supporting_evidence_ids.contains(&claim.claim_id) {
+                continue;
+            }
+            candidates.push(Candidate {
+                text: format!("Tell me about {}.", claim.source_description.trim_end_matches('.')),
+                score: 0.5 * similarity as f64,
+            });
+        }
+    }
+
+    candidates.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.text.cmp(&b.text)));
+    let mut seen: Vec<HashSet<String>> = already_asked.iter().map(|asked| tokenize(asked)).collect();
+    let mut suggestions = Vec::new();
+    for candidate in candidates {
+        if suggestions.len() >= config.max_suggestions {
+            break;
+        }
+        let tokens = tokenize(&candidate.text);
+        if seen.iter().any(|earlier| token_set_similarity(&tokens, earlier) >= config.duplicate_similarity) {
+            continue;
+        }
+        seen.push(tokens);
+        suggestions.push(candidate.text);
+    }
+    suggestions
+}
+
+/// Sentences of at least three words.
+fn sub_claims(hypothesis: &str) -> impl Iterator<Item = &str> {
+    hypothesis
+        .split(['.', '!', '?', ';'])
+        .map(str::trim)
+        .filter(|sentence| sentence.split_whitespace().count() >= 3)
+}
+
+fn lowercase_first(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mythos_memory_core::{HistoricalClaim, InMemoryMythosGraph, ProvenanceData};
+    use crate::recursive_cognition_engine::EthicalAssessmentReport;
+
+    fn claim(claim_id: &str, narrative: &str, source: &str) -> HistoricalClaim {
+        HistoricalClaim {
+            claim_id: claim_id.to_string(),
+            narrative_content: narrative.to_string(),
+            source_description: source.to_string(),
+            cultural_context_tags: vec![],
+            provenance: ProvenanceData { document_id: "doc".to_string(), author_id: "author".to_string(), timestamp: 0, cryptographic_signature: None },
+        }
+    }
+
+    fn state() -> CognitiveState {
+        CognitiveState {
+            stimulus_text: "When was the treaty of Westphalia signed?".to_string(),
+            current_hypothesis: "The treaty of Westphalia was signed in 1648. It ended the war in every province".to_string(),
+            confidence_level: 0.5,
+            supporting_evidence_ids: vec!["claim_signed".to_string()],
+            ethical_assessment: Some(EthicalAssessmentReport {
+                pas_score: 0.9,
+                ethical_concerns: vec!["Stimulus source is not recorded".to_string()],
+                alignment_status: AlignmentStatus::Aligned,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn graph() -> InMemoryMythosGraph {
+        let graph = InMemoryMythosGraph::new();
+        graph.add_historical_claim(&claim("claim_signed", "The treaty of Westphalia was signed in 1648", "Münster records")).unwrap();
+        graph.add_historical_claim(&claim("claim_peace", "Peace of Westphalia treaty negotiations", "Osnabrück envoys' letters")).unwrap();
+        graph
+    }
+
+    #[test]
+    fn test_suggestions_are_ranked_from_concerns_claims_and_narratives() {
+        let graph = graph();
+        let config = SuggestionConfig { max_suggestions: 5, ..Default::default() };
+        let suggestions = suggest_follow_ups(&state(), &[], Some(&graph), &config);
+        assert_eq!(
+            suggestions,
+            vec![
+                "How could the concern \"Stimulus source is not recorded\" be addressed?".to_string(),
+                "What supports the claim that it ended the war in every province?".to_string(),
+                "Tell me about Osnabrück envoys' letters.".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_suggestions_skip_what_the_session_already_asked() {
+        let graph = graph();
+        let asked = vec!["How could the concern 'stimulus source is not recorded' be addressed".to_string()];
+        let config = SuggestionConfig { max_suggestions: 1, ..Default::default() };
+        let suggestions = suggest_follow_ups(&state(), &asked, Some(&graph), &config);
+        assert_eq!(suggestions, vec!["What supports the claim that it ended the war in every province?".to_string()]);
+
+        let confident = CognitiveState { confidence_level: 0.95, ethical_assessment: None, ..state() };
+        assert!(suggest_follow_ups(&confident, &[], None, &SuggestionConfig::default()).is_empty());
+    }
+}
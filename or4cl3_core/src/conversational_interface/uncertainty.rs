@@ -0,0 +1,260 @@
+// or4cl3_core/src/conversational_interface/uncertainty.rs
+
+//! Graded epistemic uncertainty for responses. The uncertainty of a state
+//! (one minus its calibrated confidence) is split across the sources that
+//! explain it, and the confidence is mapped to a verbal scale for users.
+//! Explanations are written in the language of the state's `locale` metadata.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::recursive_cognition_engine::envelope::LOCALE_KEY;
+use crate::recursive_cognition_engine::evidence::EvidenceOutcome;
+use crate::recursive_cognition_engine::validation::ValidationIssue;
+use crate::recursive_cognition_engine::{AlignmentStatus, CognitiveState};
+
+use super::locale::{fill, templates_for, Templates, DEFAULT_LOCALE};
+use super::EpistemicUncertaintyInfo;
+
+/// Confidence below this gets a written explanation.
+const EXPLAIN_BELOW: f64 = 0.7;
+/// Lower bounds of the verbal scale, highest first.
+const VERBAL_SCALE: [(f64, VerbalConfidence); 4] = [
+    (0.9, VerbalConfidence::AlmostCertain),
+    (0.7, VerbalConfidence::Likely),
+    (0.4, VerbalConfidence::Uncertain),
+    (0.2, VerbalConfidence::Unlikely),
+];
+/// Each ethical concern adds this much ethical ambiguity to an assessment that did not require review.
+const AMBIGUITY_PER_CONCERN: f64 = 0.25;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VerbalConfidence {
+    AlmostCertain,
+    Likely,
+    #[default]
+    Uncertain,
+    Unlikely,
+    VeryUnlikely,
+}
+
+impl VerbalConfidence {
+    pub fn from_level(level: f64) -> Self {
+        VERBAL_SCALE
+            .iter()
+            .find(|(lower_bound, _)| level >= *lower_bound)
+            .map(|(_, verbal)| *verbal)
+            .unwrap_or(VerbalConfidence::VeryUnlikely)
+    }
+}
+
+impl fmt::Display for VerbalConfidence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerbalConfidence::AlmostCertain => write!(f, "almost certain"),
+            VerbalConfidence::Likely => write!(f, "likely"),
+            VerbalConfidence::Uncertain => write!(f, "uncertain"),
+            VerbalConfidence::Unlikely => write!(f, "unlikely"),
+            VerbalConfidence::VeryUnlikely => write!(f, "very unlikely"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UncertaintySource {
+    MissingEvidence,     // No evidence linked, or linked evidence that could not be resolved
+    ConflictingEvidence, // Evidence the hypothesis contradicts
+    EthicalAmbiguity,    // Concerns, a review verdict, or no assessment at all
+    OutOfDistribution,   // Non-text input, or input nothing in the Mythos Memory Core resembles
+}
+
+impl fmt::Display for UncertaintySource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UncertaintySource::MissingEvidence => write!(f, "missing evidence"),
+            UncertaintySource::ConflictingEvidence => write!(f, "conflicting evidence"),
+            UncertaintySource::EthicalAmbiguity => write!(f, "ethical ambiguity"),
+            UncertaintySource::OutOfDistribution => write!(f, "unfamiliar input"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UncertaintyContribution {
+    pub source: UncertaintySource,
+    pub contribution: f64, // Share of the total uncertainty (1 - level); contributions sum to it
+    pub explanation: String,
+}
+
+/// Uncertainty of `state`, with its sources ranked by contribution. Each source is
+/// weighted from 0 to 1 by its signals in the state; the total uncertainty is shared
+/// out in proportion to the weights. Confidence below 0.7 gets an explanation.
+pub fn epistemic_uncertainty(state: &CognitiveState) -> EpistemicUncertaintyInfo {
+    let templates = templates_for(state.stimulus_metadata.get(LOCALE_KEY).map(String::as_str).unwrap_or(DEFAULT_LOCALE));
+    let level = state.confidence_level.clamp(0.0, 1.0);
+    let verbal_level = VerbalConfidence::from_level(level);
+    let weighted = source_weights(state, templates);
+    let total_weight: f64 = weighted.iter().map(|(_, weight, _)| weight).sum();
+    let mut sources: Vec<UncertaintyContribution> = weighted
+        .into_iter()
+        .map(|(source, weight, explanation)| UncertaintyContribution {
+            source,
+            contribution: (1.0 - level) * weight / total_weight,
+            explanation,
+        })
+        .collect();
+    sources.sort_by(|a, b| b.contribution.total_cmp(&a.contribution));
+
+    let explanation = (level < EXPLAIN_BELOW).then(|| {
+        let grade = if level >= 0.4 { templates.grades[0] } else if level >= 0.2 { templates.grades[1] } else { templates.grades[2] };
+        let mut sentences = vec![fill(templates.confidence_is, &[grade]), fill(templates.answer_is, &[templates.verbal(verbal_level)])];
+        if sources.is_empty() {
+            sentences.push(templates.no_source.to_string());
+        }
+        for source in &sources {
+            let contribution = format!("{:.2}", source.contribution);
+            sentences.push(fill(templates.accounts_for, &[templates.source(source.source), &contribution, &source.explanation]));
+        }
+        sentences.join(" ")
+    });
+    EpistemicUncertaintyInfo { level, explanation, verbal_level, sources }
+}
+
+/// Sources with a positive weight, each with the reason it applies.
+fn source_weights(state: &CognitiveState, templates: &Templates) -> Vec<(UncertaintySource, f64, String)> {
+    let mut weights = Vec::new();
+    let found = |issue: ValidationIssue| state.validation_issues.contains(&issue);
+    let evidence_count = state.supporting_evidence_ids.len();
+    let unfamiliar = state.evidence_outcome == Some(EvidenceOutcome::NoRelevantClaims);
+
+    if state.stimulus_text.trim().is_empty() {
+        weights.push((UncertaintySource::OutOfDistribution, 1.0, templates.not_text.to_string()));
+    } else if unfamiliar && evidence_count == 0 {
+        weights.push((UncertaintySource::OutOfDistribution, 1.0, templates.unfamiliar.to_string()));
+    }
+
+    if evidence_count == 0 {
+        if !unfamiliar {
+            weights.push((UncertaintySource::MissingEvidence, 1.0, templates.no_evidence.to_string()));
+        }
+    } else {
+        let unresolved = state
+            .supporting_evidence_ids
+            .iter()
+            .filter(|claim_id| found(ValidationIssue::UnresolvedEvidence { claim_id: claim_id.to_string() }))
+            .count();
+        if unresolved > 0 {
+            weights.push((
+                UncertaintySource::MissingEvidence,
+                unresolved as f64 / evidence_count as f64,
+                fill(templates.unresolved, &[&unresolved.to_string(), &evidence_count.to_string()]),
+            ));
+        }
+        let contradicted: Vec<&str> = state
+            .supporting_evidence_ids
+            .iter()
+            .filter(|claim_id| found(ValidationIssue::ContradictedByEvidence { claim_id: claim_id.to_string() }))
+            .map(String::as_str)
+            .collect();
+        if !contradicted.is_empty() {
+            weights.push((
+                UncertaintySource::ConflictingEvidence,
+                contradicted.len() as f64 / evidence_count as f64,
+                fill(templates.contradicts, &[&contradicted.join(", ")]),
+            ));
+        }
+    }
+
+    match &state.ethical_assessment {
+        None => weights.push((UncertaintySource::EthicalAmbiguity, 0.5, templates.no_assessment.to_string())),
+        Some(assessment) if assessment.alignment_status == AlignmentStatus::RequiresReview => weights.push((
+            UncertaintySource::EthicalAmbiguity,
+            1.0,
+            fill(templates.requires_review, &[&format!("{:.2}", assessment.pas_score)]),
+        )),
+        Some(assessment) if !assessment.ethical_concerns.is_empty() => weights.push((
+            UncertaintySource::EthicalAmbiguity,
+            (assessment.ethical_concerns.len() as f64 * AMBIGUITY_PER_CONCERN).min(1.0),
+            fill(templates.concerns, &[&assessment.ethical_concerns.len().to_string()]),
+        )),
+        Some(_) => {}
+    }
+    weights
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::recursive_cognition_engine::EthicalAssessmentReport;
+
+    #[test]
+    fn test_verbal_scale_follows_the_calibrated_level() {
+        assert_eq!(VerbalConfidence::from_level(0.95), VerbalConfidence::AlmostCertain);
+        assert_eq!(VerbalConfidence::from_level(0.7), VerbalConfidence::Likely);
+        assert_eq!(VerbalConfidence::from_level(0.55), VerbalConfidence::Uncertain);
+        assert_eq!(VerbalConfidence::from_level(0.2), VerbalConfidence::Unlikely);
+        assert_eq!(VerbalConfidence::from_level(0.05), VerbalConfidence::VeryUnlikely);
+
+        let confident = CognitiveState {
+            stimulus_text: "When was the treaty signed?".to_string(),
+            confidence_level: 0.92,
+            supporting_evidence_ids: vec!["claim_treaty".to_string()],
+            ethical_assessment: Some(EthicalAssessmentReport {
+                pas_score: 0.95,
+                alignment_status: AlignmentStatus::Aligned,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let uncertainty = epistemic_uncertainty(&confident);
+        assert_eq!(uncertainty.verbal_level, VerbalConfidence::AlmostCertain);
+        assert!(uncertainty.explanation.is_none());
+        assert!(uncertainty.sources.is_empty());
+
+        // Responses serialized before `verbal_level` existed still parse
+        let legacy: EpistemicUncertaintyInfo = serde_json::from_str(r#"{"level": 0.55, "explanation": null}"#).unwrap();
+        assert_eq!(legacy.verbal_level, VerbalConfidence::Uncertain);
+    }
+
+    #[test]
+    fn test_uncertainty_is_shared_across_its_sources() {
+        let state = CognitiveState {
+            stimulus_text: "When was the treaty signed?".to_string(),
+            confidence_level: 0.4,
+            supporting_evidence_ids: vec!["claim_treaty".to_string(), "claim_lost".to_string()],
+            ethical_assessment: Some(EthicalAssessmentReport {
+                alignment_status: AlignmentStatus::RequiresReview,
+                pas_score: 0.6,
+                ..Default::default()
+            }),
+            validation_issues: vec![
+                ValidationIssue::ContradictedByEvidence { claim_id: "claim_treaty".to_string() },
+                ValidationIssue::UnresolvedEvidence { claim_id: "claim_lost".to_string() },
+            ],
+            ..Default::default()
+        };
+        let uncertainty = epistemic_uncertainty(&state);
+        let shares: Vec<(UncertaintySource, String)> =
+            uncertainty.sources.iter().map(|source| (source.source, format!("{:.2}", source.contribution))).collect();
+        assert_eq!(
+            shares,
+            vec![
+                (UncertaintySource::EthicalAmbiguity, "0.30".to_string()),
+                (UncertaintySource::MissingEvidence, "0.15".to_string()),
+                (UncertaintySource::ConflictingEvidence, "0.15".to_string()),
+            ]
+        );
+        assert_eq!(
+            uncertainty.explanation.as_deref(),
+            Some(
+                "Confidence is moderate. The answer is uncertain. \
+                 Ethical ambiguity accounts for 0.30: the ethical assessment requires review (PAS 0.60). \
+                 Missing evidence accounts for 0.15: 1 of 2 evidence claim(s) could not be found. \
+                 Conflicting evidence accounts for 0.15: the answer contradicts evidence claim_treaty."
+            )
+        );
+    }
+}
@@ -1,5 +1,8 @@
 // or4cl3_core/src/mythos_memory_core/mod.rs
 use std::collections::HashMap;
+use std::sync::RwLock;
+
+use crate::utils::jaccard_similarity;
 
 #[derive(Debug, Clone)]
 pub struct ProvenanceData {
@@ -107,6 +110,17 @@ pub trait MythosKnowledgeGraph {
         context_tag: &str,
     ) -> Result<Vec<HistoricalClaim>, String>;
 
+    /// Finds narratives whose content is similar to the given text.
+    /// Returns up to `limit` claims paired with their similarity score (0.0 to 1.0),
+    /// most similar first. Graphs without similarity search find nothing.
+    fn find_similar_narratives(
+        &self,
+        _text: &str,
+        _limit: usize,
+    ) -> Result<Vec<(HistoricalClaim, f32)>, String> {
+        Ok(vec![])
+    }
+
     // Future methods could include:
     // fn update_claim_validation_score(&self, claim_id: &str, score: &ValidationScore) -> Result<(), String>;
     // fn get_claims_by_source(&self, source_description: &str) -> Result<Vec<HistoricalClaim>, String>;
@@ -201,6 +215,115 @@ impl MythosKnowledgeGraph for Neo4jMythosGraph {
         println!("[Neo4jMythosGraph] Getting narratives for context tag: '{}' (mock)", context_tag);
         Ok(vec![]) // Return empty vector for now
     }
+
+    fn find_similar_narratives(
+        &self,
+        text: &str,
+        limit: usize,
+    ) -> Result<Vec<(HistoricalClaim, f32)>, String> {
+        // Placeholder logic:
+        // 1. Query a full-text index over narrative content:
+        //    - CALL db.index.fulltext.queryNodes("narrativeContent", text) YIELD node, score
+        //    - RETURN node, score ORDER BY score DESC LIMIT limit
+        // 2. Normalize the Lucene scores into 0.0..1.0 and map nodes to HistoricalClaim.
+        println!("[Neo4jMythosGraph] Finding up to {} narratives similar to: '{}' (mock)", limit, text);
+        Ok(vec![]) // Return empty vector for now
+    }
+}
+
+/// In-process implementation of MythosKnowledgeGraph.
+/// Useful for tests and local runs where no graph database is available.
+/// Similarity search uses token overlap (Jaccard) over narrative content.
+pub struct InMemoryMythosGraph {
+    claims: RwLock<HashMap<String, HistoricalClaim>>,
+    relationships: RwLock<Vec<(String, String, String)>>, // (from_claim_id, relationship_type, to_claim_id)
+}
+
+impl InMemoryMythosGraph {
+    pub fn new() -> Self {
+        Self {
+            claims: RwLock::new(HashMap::new()),
+            relationships: RwLock::new(Vec::new()),
+        }
+    }
+
+    /// Records a directed relationship between two stored claims, e.g. "SUPPORTS".
+    pub fn add_relationship(&self, from_claim_id: &str, relationship_type: &str, to_claim_id: &str) -> Result<(), String> {
+        let claims = self.claims.read().map_err(|_| "Mythos graph lock poisoned".to_string())?;
+        for claim_id in [from_claim_id, to_claim_id] {
+            if !claims.contains_key(claim_id) {
+                return Err(format!("Claim {} not found", claim_id));
+            }
+        }
+        let mut relationships = self.relationships.write().map_err(|_| "Mythos graph lock poisoned".to_string())?;
+        relationships.push((from_claim_id.to_string(), relationship_type.to_string(), to_claim_id.to_string()));
+        Ok(())
+    }
+}
+
+impl Default for InMemoryMythosGraph {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MythosKnowledgeGraph for InMemoryMythosGraph {
+    fn add_historical_claim(&self, claim: &HistoricalClaim) -> Result<String, String> {
+        let mut claims = self.claims.write().map_err(|_| "Mythos graph lock poisoned".to_string())?;
+        claims.insert(claim.claim_id.clone(), claim.clone());
+        Ok(claim.claim_id.clone())
+    }
+
+    fn get_historical_claim_by_id(&self, claim_id: &str) -> Result<Option<HistoricalClaim>, String> {
+        let claims = self.claims.read().map_err(|_| "Mythos graph lock poisoned".to_string())?;
+        Ok(claims.get(claim_id).cloned())
+    }
+
+    fn get_related_narratives(
+        &self,
+        claim_id: &str,
+        relationship_type: &str,
+    ) -> Result<Vec<HistoricalClaim>, String> {
+        let claims = self.claims.read().map_err(|_| "Mythos graph lock poisoned".to_string())?;
+        let relationships = self.relationships.read().map_err(|_| "Mythos graph lock poisoned".to_string())?;
+        Ok(relationships
+            .iter()
+            .filter(|(from, rel, _)| from == claim_id && rel == relationship_type)
+            .filter_map(|(_, _, to)| claims.get(to).cloned())
+            .collect())
+    }
+
+    fn get_narratives_by_context_tag(
+        &self,
+        context_tag: &str,
+    ) -> Result<Vec<HistoricalClaim>, String> {
+        let claims = self.claims.read().map_err(|_| "Mythos graph lock poisoned".to_string())?;
+        let mut matches: Vec<HistoricalClaim> = claims
+            .values()
+            .filter(|claim| claim.cultural_context_tags.iter().any(|tag| tag == context_tag))
+            .cloned()
+            .collect();
+        matches.sort_by(|a, b| a.claim_id.cmp(&b.claim_id)); // Deterministic order
+        Ok(matches)
+    }
+
+    fn find_similar_narratives(
+        &self,
+        text: &str,
+        limit: usize,
+    ) -> Result<Vec<(HistoricalClaim, f32)>, String> {
+        let claims = self.claims.read().map_err(|_| "Mythos graph lock poisoned".to_string())?;
+        let mut scored: Vec<(HistoricalClaim, f32)> = claims
+            .values()
+            .map(|claim| (claim.clone(), jaccard_similarity(text, &claim.narrative_content)))
+            .filter(|(_, similarity)| *similarity > 0.0)
+            .collect();
+        scored.sort_by(|(a, score_a), (b, score_b)| {
+            score_b.total_cmp(score_a).then_with(|| a.claim_id.cmp(&b.claim_id))
+        });
+        scored.truncate(limit);
+        Ok(scored)
+    }
 }
 
 #[cfg(test)]
@@ -312,4 +435,59 @@ mod tests {
         assert!(result.is_ok());
         assert!(result.unwrap().is_empty()); // Mock returns empty vec
     }
+
+    // --- Tests for InMemoryMythosGraph ---
+
+    fn create_claim(claim_id: &str, narrative_content: &str, tags: &[&str]) -> HistoricalClaim {
+        HistoricalClaim {
+            claim_id: claim_id.to_string(),
+            narrative_content: narrative_content.to_string(),
+            source_description: "In-memory test source.".to_string(),
+            cultural_context_tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            provenance: ProvenanceData {
+                document_id: format!("doc_{}", claim_id),
+                author_id: "author_memory".to_string(),
+                timestamp: 1678886400,
+                cryptographic_signature: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_in_memory_graph_tags_and_relationships() {
+        let graph = InMemoryMythosGraph::new();
+        graph.add_historical_claim(&create_claim("claim_a", "Redlining shaped urban housing.", &["urban_surveillance"])).unwrap();
+        graph.add_historical_claim(&create_claim("claim_b", "Archives document redlining maps.", &["urban_surveillance"])).unwrap();
+        graph.add_historical_claim(&create_claim("claim_c", "Informed consent in trials.", &["medical_ethics"])).unwrap();
+        graph.add_relationship("claim_b", "SUPPORTS", "claim_a").unwrap();
+
+        let tagged = graph.get_narratives_by_context_tag("urban_surveillance").unwrap();
+        assert_eq!(tagged.iter().map(|c| c.claim_id.as_str()).collect::<Vec<_>>(), vec!["claim_a", "claim_b"]);
+
+        let related = graph.get_related_narratives("claim_b", "SUPPORTS").unwrap();
+        assert_eq!(related.len(), 1);
+        assert_eq!(related[0].claim_id, "claim_a");
+
+        assert!(graph.add_relationship("claim_a", "SUPPORTS", "missing_claim").is_err());
+    }
+
+    #[test]
+    fn test_in_memory_graph_similarity_search() {
+        let graph = InMemoryMythosGraph::new();
+        graph.add_historical_claim(&create_claim("claim_a", "Redlining shaped urban housing policy.", &[])).unwrap();
+        graph.add_historical_claim(&create_claim("claim_b", "Informed consent in medical trials.", &[])).unwrap();
+
+        let similar = graph.find_similar_narratives("urban housing policy history", 5).unwrap();
+        assert_eq!(similar.len(), 1);
+        assert_eq!(similar[0].0.claim_id, "claim_a");
+        assert!(similar[0].1 > 0.0 && similar[0].1 <= 1.0);
+    }
+
+    #[test]
+    fn test_neo4j_find_similar_narratives_mock() {
+        let graph_db = Neo4jMythosGraph::new("neo4j://localhost:7687");
+        let result = graph_db.find_similar_narratives("some narrative", 3);
+        assert!(result.is_ok());
+        assert!(result.unwrap().is_empty()); // Mock returns empty vec
+    }
 }
@@ -1,4 +1,430 @@
 // or4cl3_core/src/polyethical_manifold/mod.rs
+
+//! Polyethical Manifold
+//! Represents ethical frameworks as loadable rulebooks and evaluates cognitive
+//! states against them. Each rule maps a condition on the state to a concern,
+//! a severity and a suggested mitigation; the triggered rules determine the
+//! PAS (Phase-Autonomous Sovereignty) score of the resulting assessment.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::recursive_cognition_engine::envelope::{canonical_locale, ConsentScope, Sensitivity, CONSENT_KEY, LOCALE_KEY, SENSITIVITY_KEY};
+use crate::recursive_cognition_engine::learning::{read_parameter, rule_weight_key, SharedParameters};
+use crate::recursive_cognition_engine::{CognitiveState, EthicalAssessmentReport, EthicalAssessor, PasThresholds};
+
 pub fn placeholder_function() -> String {
     "Hello from Polyethical Manifold".to_string()
 }
+
+/// Built-in rulebook used when no rulebook file is supplied.
+pub const DEFAULT_RULEBOOK: &str = "\
+# version: builtin-2
+# rule_id | conditions (joined by &&) | severity | concern | mitigation | weight (optional)
+privacy_surveillance | stimulus_contains:surveil | high | Request may enable surveillance of individuals | Limit analysis to aggregated, de-identified data
+privacy_personal_data | stimulus_contains:personal data && consent_missing:personal_data | high | Personal data referenced without recorded consent | Obtain and record consent before processing personal data
+privacy_confidential_data | sensitivity_at_least:confidential && consent_missing:data_processing | medium | Confidential data processed without data-processing consent | Record data_processing consent before processing confidential data
+medical_advice | stimulus_contains:diagnos | medium | Response may be taken as medical advice | Recommend consulting a qualified medical professional
+historical_sensitivity | metadata_equals:context_tags=historical_trauma | medium | Topic involves historical trauma for affected communities | Present multiple community perspectives and cite sources
+unverified_source | metadata_missing:source | low | Stimulus source is not recorded | Record stimulus provenance before relying on the response
+";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+impl Severity {
+    /// PAS penalty applied when a rule of this severity triggers (before rule weighting).
+    pub fn penalty(&self) -> f64 {
+        match self {
+            Severity::Low => 0.02,
+            Severity::Medium => 0.05,
+            Severity::High => 0.12,
+            Severity::Critical => 0.30,
+        }
+    }
+
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_lowercase().as_str() {
+            "low" => Ok(Severity::Low),
+            "medium" => Ok(Severity::Medium),
+            "high" => Ok(Severity::High),
+            "critical" => Ok(Severity::Critical),
+            other => Err(format!("Unknown severity '{}'", other)),
+        }
+    }
+}
+
+/// A single condition evaluated against a cognitive state.
+/// Text comparisons are case-insensitive.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RuleCondition {
+    HypothesisContains(String),
+    StimulusContains(String), // Matches the stimulus text or the current hypothesis
+    MetadataEquals { key: String, value: String }, // Comma-separated metadata values match on any element
+    MetadataMissing(String),
+    Locale(String), // Matches the `locale` metadata exactly, or any region of a bare language, e.g. `de` matches `de-AT`
+    ConsentIncludes(ConsentScope), // The comma-separated `consent` metadata grants the scope
+    ConsentMissing(ConsentScope),
+    SensitivityAtLeast(Sensitivity), // Absent or unrecognized `sensitivity` metadata never matches
+}
+
+impl RuleCondition {
+    /// Parses `kind:argument`, e.g. `metadata_equals:context_tags=folklore` or `locale:de`.
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let (kind, argument) = value
+            .trim()
+            .split_once(':')
+            .ok_or_else(|| format!("Condition '{}' is missing a ':'", value.trim()))?;
+        let argument = argument.trim();
+        if argument.is_empty() {
+            return Err(format!("Condition '{}' has an empty argument", kind));
+        }
+        match kind.trim() {
+            "hypothesis_contains" => Ok(RuleCondition::HypothesisContains(argument.to_lowercase())),
+            "stimulus_contains" => Ok(RuleCondition::StimulusContains(argument.to_lowercase())),
+            "metadata_equals" => {
+                let (key, value) = argument
+                    .split_once('=')
+                    .ok_or_else(|| format!("metadata_equals '{}' must be key=value", argument))?;
+                Ok(RuleCondition::MetadataEquals { key: key.trim().to_string(), value: value.trim().to_lowercase() })
+            }
+            "metadata_missing" => Ok(RuleCondition::MetadataMissing(argument.to_string())),
+            "locale" => canonical_locale(argument)
+                .map(|locale| RuleCondition::Locale(locale.to_lowercase()))
+                .map_err(|reason| format!("locale '{}' is invalid: {}", argument, reason)),
+            "consent_includes" => ConsentScope::parse(argument).map(RuleCondition::ConsentIncludes),
+            "consent_missing" => ConsentScope::parse(argument).map(RuleCondition::ConsentMissing),
+            "sensitivity_at_least" => Sensitivity::parse(argument).map(RuleCondition::SensitivityAtLeast),
+            other => Err(format!("Unknown condition kind '{}'", other)),
+        }
+    }
+
+    pub fn matches(&self, state: &CognitiveState) -> bool {
+        match self {
+            RuleCondition::HypothesisContains(needle) => state.current_hypothesis.to_lowercase().contains(needle),
+            RuleCondition::StimulusContains(needle) => {
+                state.stimulus_text.to_lowercase().contains(needle)
+                    || state.current_hypothesis.to_lowercase().contains(needle)
+            }
+            RuleCondition::MetadataEquals { key, value } => state
+                .stimulus_metadata
+                .get(key)
+                .map(|actual| actual.split(',').any(|element| element.trim().to_lowercase() == *value))
+                .unwrap_or(false),
+            RuleCondition::MetadataMissing(key) => state
+                .stimulus_metadata
+                .get(key)
+                .map(|actual| actual.trim().is_empty())
+                .unwrap_or(true),
+            RuleCondition::Locale(locale) => state
+                .stimulus_metadata
+                .get(LOCALE_KEY)
+                .and_then(|actual| canonical_locale(actual).ok())
+                .map(|actual| {
+                    let actual = actual.to_lowercase();
+                    actual == *locale || actual.starts_with(&format!("{}-", locale))
+                })
+                .unwrap_or(false),
+            RuleCondition::ConsentIncludes(scope) => consent_includes(state, *scope),
+            RuleCondition::ConsentMissing(scope) => !consent_includes(state, *scope),
+            RuleCondition::SensitivityAtLeast(level) => state
+                .stimulus_metadata
+                .get(SENSITIVITY_KEY)
+                .and_then(|actual| Sensitivity::parse(actual).ok())
+                .map(|actual| actual >= *level)
+                .unwrap_or(false),
+        }
+    }
+}
+
+/// Unrecognized scopes in the `consent` metadata grant nothing.
+fn consent_includes(state: &CognitiveState, scope: ConsentScope) -> bool {
+    state
+        .stimulus_metadata
+        .get(CONSENT_KEY)
+        .map(|actual| actual.split(',').any(|element| ConsentScope::parse(element) == Ok(scope)))
+        .unwrap_or(false)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EthicalRule {
+    pub rule_id: String,
+    pub conditions: Vec<RuleCondition>, // All conditions must hold for the rule to trigger
+    pub severity: Severity,
+    pub concern: String,
+    pub mitigation: String,
+    pub weight: f64, // Scales the severity penalty; 1.0 unless the rulebook overrides it
+}
+
+impl EthicalRule {
+    pub fn matches(&self, state: &CognitiveState) -> bool {
+        self.conditions.iter().all(|condition| condition.matches(state))
+    }
+}
+
+/// An ordered collection of ethical rules with a version label.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EthicalRulebook {
+    pub version: String,
+    pub rules: Vec<EthicalRule>,
+}
+
+impl EthicalRulebook {
+    /// Parses the pipe-separated rulebook format (see `DEFAULT_RULEBOOK`).
+    /// Blank lines and `#` comments are ignored; a `# version: <label>` comment sets the version.
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let mut version = "unversioned".to_string();
+        let mut rules: Vec<EthicalRule> = Vec::new();
+
+        for (index, line) in source.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(comment) = line.strip_prefix('#') {
+                if let Some(label) = comment.trim().strip_prefix("version:") {
+                    version = label.trim().to_string();
+                }
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split('|').map(str::trim).collect();
+            if fields.len() != 5 && fields.len() != 6 {
+                return Err(format!("Line {}: expected 5 or 6 '|' separated fields, found {}", line_number, fields.len()));
+            }
+            let rule_id = fields[0].to_string();
+            if rule_id.is_empty() {
+                return Err(format!("Line {}: rule_id cannot be empty", line_number));
+            }
+            if rules.iter().any(|rule| rule.rule_id == rule_id) {
+                return Err(format!("Line {}: duplicate rule_id '{}'", line_number, rule_id));
+            }
+            let conditions = fields[1]
+                .split("&&")
+                .map(RuleCondition::parse)
+                .collect::<Result<Vec<_>, String>>()
+                .map_err(|e| format!("Line {}: {}", line_number, e))?;
+            let severity = Severity::parse(fields[2]).map_err(|e| format!("Line {}: {}", line_number, e))?;
+            let weight = match fields.get(5) {
+                Some(raw) => raw
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|weight| *weight >= 0.0)
+                    .ok_or_else(|| format!("Line {}: weight '{}' must be a non-negative number", line_number, raw))?,
+                None => 1.0,
+            };
+
+            rules.push(EthicalRule {
+                rule_id,
+                conditions,
+                severity,
+                concern: fields[3].to_string(),
+                mitigation: fields[4].to_string(),
+                weight,
+            });
+        }
+
+        Ok(Self { version, rules })
+    }
+
+    pub fn load_from_file(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let source = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read rulebook {}: {}", path.display(), e))?;
+        Self::parse(&source)
+    }
+
+    pub fn builtin() -> Self {
+        Self::parse(DEFAULT_RULEBOOK).expect("built-in rulebook must parse")
+    }
+}
+
+/// EthicalAssessor that evaluates a cognitive state against an `EthicalRulebook`.
+/// The PAS score starts at 1.0 and is reduced by the weighted penalty of each triggered rule.
+pub struct RulebookEthicalAssessor {
+    rulebook: EthicalRulebook,
+    thresholds: PasThresholds,
+    parameters: Option<SharedParameters>, // Learned rule weight multipliers, if any
+}
+
+impl RulebookEthicalAssessor {
+    pub fn new(rulebook: EthicalRulebook) -> Self {
+        Self { rulebook, thresholds: PasThresholds::default(), parameters: None }
+    }
+
+    /// Multiplies each rule's weight by its learned `rule_weight.<rule_id>` parameter.
+    pub fn with_parameters(mut self, parameters: SharedParameters) -> Self {
+        self.parameters = Some(parameters);
+        self
+    }
+
+    pub fn with_thresholds(mut self, thresholds: PasThresholds) -> Self {
+        self.thresholds = thresholds;
+        self
+    }
+
+    pub fn rulebook(&self) -> &EthicalRulebook {
+        &self.rulebook
+    }
+}
+
+impl EthicalAssessor for RulebookEthicalAssessor {
+    fn perform_ethical_assessment(&self, state: &CognitiveState) -> Result<EthicalAssessmentReport, String> {
+        let mut ethical_concerns: Vec<String> = Vec::new();
+        let mut suggested_mitigations: Vec<String> = Vec::new();
+        let mut pas_breakdown: HashMap<String, f64> = HashMap::new();
+
+        for rule in self.rulebook.rules.iter().filter(|rule| rule.matches(state)) {
+            let learned_weight = self
+                .parameters
+                .as_ref()
+                .map(|parameters| read_parameter(parameters, &rule_weight_key(&rule.rule_id)))
+                .unwrap_or(1.0);
+            pas_breakdown.insert(rule.rule_id.clone(), rule.severity.penalty() * rule.weight * learned_weight);
+            if !ethical_concerns.contains(&rule.concern) {
+                ethical_concerns.push(rule.concern.clone());
+            }
+            if !suggested_mitigations.contains(&rule.mitigation) {
+                suggested_mitigations.push(rule.mitigation.clone());
+            }
+        }
+
+        let pas_score = (1.0 - pas_breakdown.values().sum::<f64>()).clamp(0.0, 1.0);
+        Ok(EthicalAssessmentReport {
+            pas_score,
+            ethical_concerns,
+            suggested_mitigations,
+            alignment_status: self.thresholds.classify(pas_score),
+            pas_breakdown,
+            assessed_hypothesis: state.current_hypothesis.clone(),
+        })
+    }
+
+    fn version(&self) -> String {
+        format!("rulebook {}", self.rulebook.version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::recursive_cognition_engine::AlignmentStatus;
+
+    fn create_state(stimulus_text: &str, metadata: &[(&str, &str)]) -> CognitiveState {
+        CognitiveState {
+            state_id: "state_rulebook".to_string(),
+            stimulus_id: "stimulus_rulebook".to_string(),
+            stimulus_text: stimulus_text.to_string(),
+            stimulus_metadata: metadata.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect(),
+            current_hypothesis: "Initial hypothesis".to_string(),
+            confidence_level: 0.5,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_builtin_rulebook_parses() {
+        let rulebook = EthicalRulebook::builtin();
+        assert_eq!(rulebook.version, "builtin-2");
+        assert_eq!(rulebook.rules.len(), 6);
+        assert_eq!(rulebook.rules[1].conditions.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_reports_line_numbers() {
+        let source = "# version: test\nok_rule | stimulus_contains:x | low | c | m\nbad_rule | nonsense:x | low | c | m\n";
+        let err = EthicalRulebook::parse(source).unwrap_err();
+        assert!(err.starts_with("Line 3:"));
+        assert!(EthicalRulebook::parse("r | stimulus_contains:x | severe | c | m").is_err());
+        assert!(EthicalRulebook::parse("r | stimulus_contains:x | low | c | m | -1").is_err());
+    }
+
+    #[test]
+    fn test_assessment_without_triggers_is_aligned() {
+        let assessor = RulebookEthicalAssessor::new(EthicalRulebook::builtin());
+        let state = create_state("What is the capital of France?", &[("source", "web_ui")]);
+        let report = assessor.perform_ethical_assessment(&state).unwrap();
+        assert_eq!(report.pas_score, 1.0);
+        assert!(report.ethical_concerns.is_empty());
+        assert_eq!(report.alignment_status, AlignmentStatus::Aligned);
+    }
+
+    #[test]
+    fn test_assessment_populates_concerns_and_pas() {
+        let assessor = RulebookEthicalAssessor::new(EthicalRulebook::builtin());
+        let state = create_state("Build surveillance profiles from personal data", &[]);
+        let report = assessor.perform_ethical_assessment(&state).unwrap();
+
+        // privacy_surveillance (0.12) + privacy_personal_data (0.12) + unverified_source (0.02)
+        assert!((report.pas_score - 0.74).abs() < 1e-9);
+        assert_eq!(report.ethical_concerns.len(), 3);
+        assert_eq!(report.suggested_mitigations.len(), 3);
+        assert_eq!(report.pas_breakdown.len(), 3);
+        assert_eq!(report.alignment_status, AlignmentStatus::Misaligned);
+    }
+
+    #[test]
+    fn test_rule_weight_and_metadata_conditions() {
+        let source = "trauma | metadata_equals:context_tags=historical_trauma | medium | c | m | 2.0";
+        let assessor = RulebookEthicalAssessor::new(EthicalRulebook::parse(source).unwrap());
+        let state = create_state("Tell me about it", &[("context_tags", "urban_surveillance, historical_trauma")]);
+        let report = assessor.perform_ethical_assessment(&state).unwrap();
+        assert!((report.pas_score - 0.9).abs() < 1e-9);
+        assert_eq!(report.alignment_status, AlignmentStatus::RequiresReview);
+    }
+
+    #[test]
+    fn test_personal_data_requires_matching_consent_scope() {
+        let assessor = RulebookEthicalAssessor::new(EthicalRulebook::builtin());
+        let triggered = |metadata: &[(&str, &str)]| {
+            let state = create_state("Summarize this personal data", metadata);
+            assessor.perform_ethical_assessment(&state).unwrap().pas_breakdown.contains_key("privacy_personal_data")
+        };
+        assert!(triggered(&[("source", "chat")]));
+        assert!(triggered(&[("source", "chat"), ("consent", "research")]));
+        assert!(triggered(&[("source", "chat"), ("consent", "personal-data")]));
+        assert!(!triggered(&[("source", "chat"), ("consent", "research, PERSONAL_DATA")]));
+    }
+
+    #[test]
+    fn test_sensitivity_conditions_compare_levels() {
+        let assessor = RulebookEthicalAssessor::new(EthicalRulebook::builtin());
+        let triggered = |metadata: &[(&str, &str)]| {
+            let state = create_state("Summarize the quarterly figures", metadata);
+            assessor.perform_ethical_assessment(&state).unwrap().pas_breakdown.contains_key("privacy_confidential_data")
+        };
+        assert!(!triggered(&[("sensitivity", "internal")]));
+        assert!(triggered(&[("sensitivity", "confidential")]));
+        assert!(triggered(&[("sensitivity", "restricted"), ("consent", "research")]));
+        assert!(!triggered(&[("sensitivity", "restricted"), ("consent", "data_processing")]));
+        assert!(!triggered(&[("sensitivity", "top_secret")]));
+
+        assert_eq!(RuleCondition::parse("consent_includes:research"), Ok(RuleCondition::ConsentIncludes(ConsentScope::Research)));
+        assert!(EthicalRulebook::parse("r | sensitivity_at_least:secret | low | c | m").unwrap_err().starts_with("Line 1:"));
+        assert!(EthicalRulebook::parse("r | consent_missing:marketing | low | c | m").unwrap_err().starts_with("Line 1:"));
+    }
+
+    #[test]
+    fn test_locale_condition_matches_language_and_region() {
+        let source = "de_norms | locale:de | medium | c | m\npt_br_norms | locale:pt_br | low | c2 | m2";
+        let assessor = RulebookEthicalAssessor::new(EthicalRulebook::parse(source).unwrap());
+        let triggered = |locale: &str| {
+            let report = assessor.perform_ethical_assessment(&create_state("Hallo", &[("locale", locale)])).unwrap();
+            let mut rules: Vec<String> = report.pas_breakdown.into_keys().collect();
+            rules.sort();
+            rules
+        };
+        assert_eq!(triggered("de-AT"), vec!["de_norms".to_string()]);
+        assert_eq!(triggered("pt-BR"), vec!["pt_br_norms".to_string()]);
+        assert!(triggered("pt").is_empty());
+        assert!(triggered("en").is_empty());
+        assert!(EthicalRulebook::parse("r | locale:german | low | c | m").unwrap_err().starts_with("Line 1:"));
+    }
+}
@@ -0,0 +1,625 @@
+// or4cl3_core/src/recursive_cognition_engine/async_engine.rs
+
+//! Asynchronous variant of the Recursive Cognition Engine.
+//! Stages return boxed futures so that slow stages (remote models, graph
+//! queries) do not block the caller. Cycles run under a `CycleControl` that
+//! applies per-stage deadlines and cooperative cancellation; a cycle that is
+//! cut short returns the last completed state instead of discarding it.
+//! `AsyncComponentEngine` composes async stage components; `BlockingStage`
+//! and `BlockingEngineAdapter` bring synchronous ones over.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::Notify;
+
+use super::{
+    BasicRecursiveCognitionEngine, CognitionError, CognitionErrorKind, CognitionStage, CognitiveState, EthicalAssessmentReport,
+    EthicalAssessor, HumanFeedback, RecursiveCognitionEngine, RefinementEngine, SelfValidator, Stimulus,
+};
+
+/// Boxed future returned by every async engine stage.
+pub type StageFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T, CognitionError>> + Send + 'a>>;
+
+/// Boxed future returned by async stage components, which report plain messages like their sync counterparts.
+pub type ComponentFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T, String>> + Send + 'a>>;
+
+pub trait AsyncEthicalAssessor {
+    fn perform_ethical_assessment<'a>(&'a self, state: &'a CognitiveState) -> ComponentFuture<'a, EthicalAssessmentReport>;
+}
+
+pub trait AsyncRefinementEngine {
+    fn refine_cognitive_state<'a>(&'a self, state: &'a CognitiveState) -> ComponentFuture<'a, CognitiveState>;
+}
+
+pub trait AsyncSelfValidator {
+    fn self_validate_state<'a>(&'a self, state: &'a CognitiveState) -> ComponentFuture<'a, CognitiveState>;
+}
+
+/// Async counterpart of `RecursiveCognitionEngine`.
+pub trait AsyncRecursiveCognitionEngine: Sync {
+    fn initialize_state_from_stimulus(&self, stimulus: Stimulus) -> StageFuture<'_, CognitiveState>;
+    fn assess_ethics<'a>(&'a self, state: &'a CognitiveState) -> StageFuture<'a, CognitiveState>;
+    fn refine_cognition<'a>(&'a self, state: &'a CognitiveState) -> StageFuture<'a, CognitiveState>;
+    fn validate_self<'a>(&'a self, state: &'a CognitiveState) -> StageFuture<'a, CognitiveState>;
+    fn incorporate_feedback<'a>(&'a self, state: &'a CognitiveState, feedback: HumanFeedback) -> StageFuture<'a, CognitiveState>;
+
+    fn finalize_cycle<'a>(&'a self, state: &'a CognitiveState) -> StageFuture<'a, CognitiveState> {
+        Box::pin(async move { Ok(state.clone()) })
+    }
+
+    /// Runs all stages in order under the given control.
+    fn execute_full_cycle<'a>(
+        &'a self,
+        stimulus: Stimulus,
+        control: &'a CycleControl,
+    ) -> Pin<Box<dyn Future<Output = CycleOutcome> + Send + 'a>> {
+        Box::pin(run_cycle(self, stimulus, control))
+    }
+}
+
+/// Cooperative cancellation signal shared between a cycle and whoever may abort it.
+/// Cancelling interrupts the stage in flight at its next await point.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+    notify: Arc<Notify>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+        self.notify.notify_waiters();
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Completes once the token is cancelled.
+    pub async fn cancelled(&self) {
+        loop {
+            let notified = self.notify.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable(); // Register before checking the flag so a cancel cannot be missed
+            if self.is_cancelled() {
+                return;
+            }
+            notified.await;
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum InterruptReason {
+    Cancelled,
+    DeadlineExceeded(Duration),
+    StageFailed(CognitionError),
+}
+
+#[derive(Debug, Clone)]
+pub enum CycleOutcome {
+    Completed(CognitiveState),
+    Interrupted {
+        partial_state: Option<CognitiveState>, // Last state produced before the interruption; None on an ethical block
+        completed_stages: Vec<CognitionStage>,
+        interrupted_stage: CognitionStage,
+        reason: InterruptReason,
+    },
+}
+
+impl CycleOutcome {
+    pub fn is_complete(&self) -> bool {
+        matches!(self, CycleOutcome::Completed(_))
+    }
+
+    /// Discards any partial state and converts the outcome into a plain result.
+    /// Cancellation and deadlines become `Cancelled` and `Timeout` errors for the interrupted stage.
+    pub fn into_result(self) -> Result<CognitiveState, CognitionError> {
+        match self {
+            CycleOutcome::Completed(state) => Ok(state),
+            CycleOutcome::Interrupted { partial_state, interrupted_stage, reason, .. } => {
+                let kind = match reason {
+                    InterruptReason::StageFailed(error) => return Err(error),
+                    InterruptReason::Cancelled => CognitionErrorKind::Cancelled,
+                    InterruptReason::DeadlineExceeded(deadline) => CognitionErrorKind::Timeout(deadline),
+                };
+                let error = CognitionError::new(Some(interrupted_stage), kind);
+                Err(match partial_state {
+                    Some(state) => error.for_state(&state.state_id),
+                    None => error,
+                })
+            }
+        }
+    }
+}
+
+/// Deadlines and cancellation applied to an async cycle.
+#[derive(Debug, Clone, Default)]
+pub struct CycleControl {
+    stage_deadlines: HashMap<CognitionStage, Duration>,
+    default_deadline: Option<Duration>, // Applies to stages without their own deadline
+    cancellation: CancellationToken,
+}
+
+impl CycleControl {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_stage_deadline(mut self, stage: CognitionStage, deadline: Duration) -> Self {
+        self.stage_deadlines.insert(stage, deadline);
+        self
+    }
+
+    pub fn with_default_deadline(mut self, deadline: Duration) -> Self {
+        self.default_deadline = Some(deadline);
+        self
+    }
+
+    pub fn with_cancellation(mut self, cancellation: CancellationToken) -> Self {
+        self.cancellation = cancellation;
+        self
+    }
+
+    pub fn deadline_for(&self, stage: CognitionStage) -> Option<Duration> {
+        self.stage_deadlines.get(&stage).copied().or(self.default_deadline)
+    }
+
+    pub fn cancellation(&self) -> &CancellationToken {
+        &self.cancellation
+    }
+
+    /// Drives one stage future to completion, or stops it on cancellation or deadline.
+    pub async fn run_stage<T>(&self, stage: CognitionStage, future: StageFuture<'_, T>) -> Result<T, InterruptReason> {
+        if self.cancellation.is_cancelled() {
+            return Err(InterruptReason::Cancelled);
+        }
+        let deadline = self.deadline_for(stage);
+        let guarded = async move {
+            match deadline {
+                Some(deadline) => match tokio::time::timeout(deadline, future).await {
+                    Ok(result) => result.map_err(InterruptReason::StageFailed),
+                    Err(_) => Err(InterruptReason::DeadlineExceeded(deadline)),
+                },
+                None => future.await.map_err(InterruptReason::StageFailed),
+            }
+        };
+        tokio::select! {
+            biased;
+            _ = self.cancellation.cancelled() => Err(InterruptReason::Cancelled),
+            result = guarded => result,
+        }
+    }
+}
+
+async fn run_cycle<E: AsyncRecursiveCognitionEngine + ?Sized>(
+    engine: &E,
+    stimulus: Stimulus,
+    control: &CycleControl,
+) -> CycleOutcome {
+    let mut completed_stages = Vec::new();
+    let mut state = match control
+        .run_stage(CognitionStage::Initialization, engine.initialize_state_from_stimulus(stimulus))
+        .await
+    {
+        Ok(state) => state,
+        Err(reason) => {
+            return CycleOutcome::Interrupted {
+                partial_state: None,
+                completed_stages,
+                interrupted_stage: CognitionStage::Initialization,
+                reason,
+            }
+        }
+    };
+    completed_stages.push(CognitionStage::Initialization);
+
+    for stage in &CognitionStage::ALL[1..] {
+        let future = match stage {
+            CognitionStage::EthicalAssessment => engine.assess_ethics(&state),
+            CognitionStage::Refinement => engine.refine_cognition(&state),
+            CognitionStage::SelfValidation => engine.validate_self(&state),
+            _ => engine.finalize_cycle(&state),
+        };
+        match control.run_stage(*stage, future).await {
+            Ok(next_state) => {
+                state = next_state;
+                completed_stages.push(*stage);
+            }
+            Err(reason) => {
+                // A state the ethics gate refused never leaves the engine, not even as a partial result.
+                let blocked = matches!(&reason, InterruptReason::StageFailed(error) if matches!(error.kind, CognitionErrorKind::EthicalBlock(_)));
+                return CycleOutcome::Interrupted {
+                    partial_state: (!blocked).then_some(state),
+                    completed_stages,
+                    interrupted_stage: *stage,
+                    reason,
+                }
+            }
+        }
+    }
+    CycleOutcome::Completed(state)
+}
+
+/// Runs a synchronous engine's stages on Tokio's blocking thread pool.
+/// A stage that overruns its deadline keeps running in the background, but its result is discarded.
+pub struct BlockingEngineAdapter<E> {
+    engine: Arc<E>,
+}
+
+impl<E> BlockingEngineAdapter<E> {
+    pub fn new(engine: E) -> Self {
+        Self { engine: Arc::new(engine) }
+    }
+
+    pub fn from_shared(engine: Arc<E>) -> Self {
+        Self { engine }
+    }
+}
+
+fn spawn_stage<E, F>(engine: &Arc<E>, stage: Option<CognitionStage>, run: F) -> StageFuture<'static, CognitiveState>
+where
+    E: Send + Sync + 'static,
+    F: FnOnce(&E) -> Result<CognitiveState, CognitionError> + Send + 'static,
+{
+    let engine = engine.clone();
+    Box::pin(async move {
+        tokio::task::spawn_blocking(move || run(&engine))
+            .await
+            .map_err(|e| CognitionError::dependency(stage, "blocking_task", e.to_string(), false))?
+    })
+}
+
+impl<E: RecursiveCognitionEngine + Send + Sync + 'static> AsyncRecursiveCognitionEngine for BlockingEngineAdapter<E> {
+    fn initialize_state_from_stimulus(&self, stimulus: Stimulus) -> StageFuture<'_, CognitiveState> {
+        spawn_stage(&self.engine, Some(CognitionStage::Initialization), move |engine| engine.initialize_state_from_stimulus(stimulus))
+    }
+
+    fn assess_ethics<'a>(&'a self, state: &'a CognitiveState) -> StageFuture<'a, CognitiveState> {
+        let state = state.clone();
+        spawn_stage(&self.engine, Some(CognitionStage::EthicalAssessment), move |engine| engine.assess_ethics(&state))
+    }
+
+    fn refine_cognition<'a>(&'a self, state: &'a CognitiveState) -> StageFuture<'a, CognitiveState> {
+        let state = state.clone();
+        spawn_stage(&self.engine, Some(CognitionStage::Refinement), move |engine| engine.refine_cognition(&state))
+    }
+
+    fn validate_self<'a>(&'a self, state: &'a CognitiveState) -> StageFuture<'a, CognitiveState> {
+        let state = state.clone();
+        spawn_stage(&self.engine, Some(CognitionStage::SelfValidation), move |engine| engine.validate_self(&state))
+    }
+
+    fn incorporate_feedback<'a>(&'a self, state: &'a CognitiveState, feedback: HumanFeedback) -> StageFuture<'a, CognitiveState> {
+        let state = state.clone();
+        spawn_stage(&self.engine, None, move |engine| engine.incorporate_feedback(&state, feedback))
+    }
+
+    fn finalize_cycle<'a>(&'a self, state: &'a CognitiveState) -> StageFuture<'a, CognitiveState> {
+        let state = state.clone();
+        spawn_stage(&self.engine, Some(CognitionStage::Finalization), move |engine| engine.finalize_cycle(&state))
+    }
+}
+
+/// Exposes a synchronous stage component through the async stage traits,
+/// running it on Tokio's blocking thread pool.
+pub struct BlockingStage<T> {
+    component: Arc<T>,
+}
+
+impl<T> BlockingStage<T> {
+    pub fn new(component: T) -> Self {
+        Self { component: Arc::new(component) }
+    }
+}
+
+impl<T: EthicalAssessor + Send + Sync + 'static> AsyncEthicalAssessor for BlockingStage<T> {
+    fn perform_ethical_assessment<'a>(&'a self, state: &'a CognitiveState) -> ComponentFuture<'a, EthicalAssessmentReport> {
+        let component = self.component.clone();
+        let state = state.clone();
+        Box::pin(async move {
+            tokio::task::spawn_blocking(move || component.perform_ethical_assessment(&state))
+                .await
+                .map_err(|e| format!("Blocking stage task failed: {}", e))?
+        })
+    }
+}
+
+impl<T: RefinementEngine + Send + Sync + 'static> AsyncRefinementEngine for BlockingStage<T> {
+    fn refine_cognitive_state<'a>(&'a self, state: &'a CognitiveState) -> ComponentFuture<'a, CognitiveState> {
+        let component = self.component.clone();
+        let state = state.clone();
+        Box::pin(async move {
+            tokio::task::spawn_blocking(move || component.refine_cognitive_state(&state))
+                .await
+                .map_err(|e| format!("Blocking stage task failed: {}", e))?
+        })
+    }
+}
+
+impl<T: SelfValidator + Send + Sync + 'static> AsyncSelfValidator for BlockingStage<T> {
+    fn self_validate_state<'a>(&'a self, state: &'a CognitiveState) -> ComponentFuture<'a, CognitiveState> {
+        let component = self.component.clone();
+        let state = state.clone();
+        Box::pin(async move {
+            tokio::task::spawn_blocking(move || component.self_validate_state(&state))
+                .await
+                .map_err(|e| format!("Blocking stage task failed: {}", e))?
+        })
+    }
+}
+
+/// Async engine built from async stage components. Ethical assessment, refinement and
+/// self-validation run on their async component when one is set; every other stage,
+/// and any stage without a component, runs on the base engine's blocking thread pool.
+/// Reports from the async assessor are classified by the base engine's PAS thresholds.
+pub struct AsyncComponentEngine {
+    base: BlockingEngineAdapter<BasicRecursiveCognitionEngine>,
+    ethical_assessor: Option<Box<dyn AsyncEthicalAssessor + Send + Sync>>,
+    refinement_engine: Option<Box<dyn AsyncRefinementEngine + Send + Sync>>,
+    self_validator: Option<Box<dyn AsyncSelfValidator + Send + Sync>>,
+}
+
+impl AsyncComponentEngine {
+    pub fn new(base: BasicRecursiveCognitionEngine) -> Self {
+        Self { base: BlockingEngineAdapter::new(base), ethical_assessor: None, refinement_engine: None, self_validator: None }
+    }
+
+    /// Uses the given component for the ethical assessment stage, e.g. a `BlockingStage`
+    /// around a `polyethical_manifold::RulebookEthicalAssessor`.
+    pub fn with_ethical_assessor(mut self, ethical_assessor: impl AsyncEthicalAssessor + Send + Sync + 'static) -> Self {
+        self.ethical_assessor = Some(Box::new(ethical_assessor));
+        self
+    }
+
+    pub fn with_refinement_engine(mut self, refinement_engine: impl AsyncRefinementEngine + Send + Sync + 'static) -> Self {
+        self.refinement_engine = Some(Box::new(refinement_engine));
+        self
+    }
+
+    /// Uses the given component for the self-validation stage. As in the sync engine,
+    /// a validator that clears `ethical_assessment` forces a re-assessment.
+    pub fn with_self_validator(mut self, self_validator: impl AsyncSelfValidator + Send + Sync + 'static) -> Self {
+        self.self_validator = Some(Box::new(self_validator));
+        self
+    }
+}
+
+impl AsyncRecursiveCognitionEngine for AsyncComponentEngine {
+    fn initialize_state_from_stimulus(&self, stimulus: Stimulus) -> StageFuture<'_, CognitiveState> {
+        self.base.initialize_state_from_stimulus(stimulus)
+    }
+
+    fn assess_ethics<'a>(&'a self, state: &'a CognitiveState) -> StageFuture<'a, CognitiveState> {
+        let ethical_assessor = match &self.ethical_assessor {
+            Some(ethical_assessor) => ethical_assessor,
+            None => return self.base.assess_ethics(state),
+        };
+        Box::pin(async move {
+            let report = ethical_assessor.perform_ethical_assessment(state).await.map_err(|e| {
+                CognitionError::component(Some(CognitionStage::EthicalAssessment), "ethical_assessor", e).for_state(&state.state_id)
+            })?;
+            Ok(self.base.engine.apply_ethical_assessment(state, report))
+        })
+    }
+
+    fn refine_cognition<'a>(&'a self, state: &'a CognitiveState) -> StageFuture<'a, CognitiveState> {
+        let refinement_engine = match &self.refinement_engine {
+            Some(refinement_engine) => refinement_engine,
+            None => return self.base.refine_cognition(state),
+        };
+        Box::pin(async move {
+            refinement_engine.refine_cognitive_state(state).await.map_err(|e| {
+                CognitionError::component(Some(CognitionStage::Refinement), "refinement_engine", e).for_state(&state.state_id)
+            })
+        })
+    }
+
+    fn validate_self<'a>(&'a self, state: &'a CognitiveState) -> StageFuture<'a, CognitiveState> {
+        let self_validator = match &self.self_validator {
+            Some(self_validator) => self_validator,
+            None => return self.base.validate_self(state),
+        };
+        Box::pin(async move {
+            let validated = self_validator.self_validate_state(state).await.map_err(|e| {
+                CognitionError::component(Some(CognitionStage::SelfValidation), "self_validator", e).for_state(&state.state_id)
+            })?;
+            if validated.ethical_assessment.is_some() {
+                return Ok(validated);
+            }
+            let mut reassessed = self.assess_ethics(&validated).await?;
+            reassessed.history_log.push("Ethical re-assessment forced by self-validation".to_string());
+            Ok(reassessed)
+        })
+    }
+
+    fn incorporate_feedback<'a>(&'a self, state: &'a CognitiveState, feedback: HumanFeedback) -> StageFuture<'a, CognitiveState> {
+        self.base.incorporate_feedback(state, feedback)
+    }
+
+    fn finalize_cycle<'a>(&'a self, state: &'a CognitiveState) -> StageFuture<'a, CognitiveState> {
+        self.base.finalize_cycle(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polyethical_manifold::{EthicalRulebook, RulebookEthicalAssessor};
+    use crate::recursive_cognition_engine::validation::ConsistencySelfValidator;
+    use crate::recursive_cognition_engine::StimulusContent;
+
+    fn create_test_stimulus() -> Stimulus {
+        Stimulus {
+            id: "async_stimulus".to_string(),
+            content: StimulusContent::Text("An async stimulus.".to_string()),
+            metadata: HashMap::new(),
+        }
+    }
+
+    /// Async engine whose refinement stage sleeps (or fails) to exercise cycle control.
+    struct SlowRefinementEngine {
+        refinement_delay: Duration,
+        fail_refinement: bool,
+    }
+
+    impl AsyncRecursiveCognitionEngine for SlowRefinementEngine {
+        fn initialize_state_from_stimulus(&self, stimulus: Stimulus) -> StageFuture<'_, CognitiveState> {
+            Box::pin(async move {
+                Ok(CognitiveState { state_id: format!("state_for_{}", stimulus.id), stimulus_id: stimulus.id, ..Default::default() })
+            })
+        }
+        fn assess_ethics<'a>(&'a self, state: &'a CognitiveState) -> StageFuture<'a, CognitiveState> {
+            Box::pin(async move {
+                let mut new_state = state.clone();
+                new_state.history_log.push("assessed".to_string());
+                Ok(new_state)
+            })
+        }
+        fn refine_cognition<'a>(&'a self, state: &'a CognitiveState) -> StageFuture<'a, CognitiveState> {
+            Box::pin(async move {
+                tokio::time::sleep(self.refinement_delay).await;
+                if self.fail_refinement {
+                    return Err(CognitionError::dependency(Some(CognitionStage::Refinement), "refiner", "offline", true));
+                }
+                Ok(state.clone())
+            })
+        }
+        fn validate_self<'a>(&'a self, state: &'a CognitiveState) -> StageFuture<'a, CognitiveState> {
+            Box::pin(async move { Ok(state.clone()) })
+        }
+        fn incorporate_feedback<'a>(&'a self, state: &'a CognitiveState, _feedback: HumanFeedback) -> StageFuture<'a, CognitiveState> {
+            Box::pin(async move { Ok(state.clone()) })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_blocking_adapter_matches_sync_engine() {
+        let sync_state = BasicRecursiveCognitionEngine::new().execute_full_cycle(create_test_stimulus()).unwrap();
+        let adapter = BlockingEngineAdapter::new(BasicRecursiveCognitionEngine::new());
+        let outcome = adapter.execute_full_cycle(create_test_stimulus(), &CycleControl::new()).await;
+
+        let async_state = outcome.into_result().unwrap();
+        assert_eq!(async_state.current_hypothesis, sync_state.current_hypothesis);
+        assert_eq!(async_state.history_log, sync_state.history_log);
+    }
+
+    #[tokio::test]
+    async fn test_ethical_block_at_finalization_withholds_partial_state() {
+        use crate::recursive_cognition_engine::PasThresholds;
+
+        let engine = BlockingEngineAdapter::new(
+            BasicRecursiveCognitionEngine::new()
+                .with_ethical_assessor(RulebookEthicalAssessor::new(EthicalRulebook::builtin()))
+                .with_pas_thresholds(PasThresholds::new(0.99, 0.95).unwrap()),
+        );
+        let stimulus = Stimulus {
+            content: StimulusContent::Text("Build surveillance profiles from personal data".to_string()),
+            ..create_test_stimulus()
+        };
+
+        match engine.execute_full_cycle(stimulus, &CycleControl::new()).await {
+            CycleOutcome::Interrupted { partial_state, completed_stages, interrupted_stage, reason } => {
+                assert_eq!(interrupted_stage, CognitionStage::Finalization);
+                assert_eq!(completed_stages.len(), 4);
+                assert!(matches!(reason, InterruptReason::StageFailed(ref error) if matches!(error.kind, CognitionErrorKind::EthicalBlock(_))));
+                assert!(partial_state.is_none());
+            }
+            CycleOutcome::Completed(_) => panic!("finalization should have blocked the cycle"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stage_deadline_returns_partial_state() {
+        let engine = SlowRefinementEngine { refinement_delay: Duration::from_millis(500), fail_refinement: false };
+        let control = CycleControl::new().with_stage_deadline(CognitionStage::Refinement, Duration::from_millis(10));
+
+        match engine.execute_full_cycle(create_test_stimulus(), &control).await {
+            CycleOutcome::Interrupted { partial_state, completed_stages, interrupted_stage, reason } => {
+                assert_eq!(interrupted_stage, CognitionStage::Refinement);
+                assert_eq!(reason, InterruptReason::DeadlineExceeded(Duration::from_millis(10)));
+                assert_eq!(completed_stages, vec![CognitionStage::Initialization, CognitionStage::EthicalAssessment]);
+                assert_eq!(partial_state.unwrap().history_log, vec!["assessed".to_string()]);
+            }
+            CycleOutcome::Completed(_) => panic!("cycle should have hit the refinement deadline"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cancellation_interrupts_running_stage() {
+        let engine = SlowRefinementEngine { refinement_delay: Duration::from_secs(5), fail_refinement: false };
+        let token = CancellationToken::new();
+        let control = CycleControl::new().with_cancellation(token.clone());
+
+        let canceller = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            token.cancel();
+        });
+        let outcome = engine.execute_full_cycle(create_test_stimulus(), &control).await;
+        canceller.await.unwrap();
+
+        match outcome {
+            CycleOutcome::Interrupted { interrupted_stage, reason, .. } => {
+                assert_eq!(interrupted_stage, CognitionStage::Refinement);
+                assert_eq!(reason, InterruptReason::Cancelled);
+            }
+            CycleOutcome::Completed(_) => panic!("cycle should have been cancelled"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stage_failure_and_precancelled_cycle() {
+        let engine = SlowRefinementEngine { refinement_delay: Duration::ZERO, fail_refinement: true };
+        let err = engine.execute_full_cycle(create_test_stimulus(), &CycleControl::new()).await.into_result().unwrap_err();
+        assert_eq!(err.stage, Some(CognitionStage::Refinement));
+        assert!(err.is_retryable());
+        assert_eq!(err.to_string(), "refinement failed: refiner failed: offline");
+
+        let token = CancellationToken::new();
+        token.cancel();
+        let control = CycleControl::new().with_cancellation(token);
+        match engine.execute_full_cycle(create_test_stimulus(), &control).await {
+            CycleOutcome::Interrupted { partial_state, interrupted_stage, .. } => {
+                assert!(partial_state.is_none());
+                assert_eq!(interrupted_stage, CognitionStage::Initialization);
+            }
+            CycleOutcome::Completed(_) => panic!("pre-cancelled cycle should not run"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_blocking_stage_wraps_sync_assessor() {
+        let assessor = BlockingStage::new(RulebookEthicalAssessor::new(EthicalRulebook::builtin()));
+        let state = CognitiveState { stimulus_text: "Can you diagnose this?".to_string(), ..Default::default() };
+        let report = assessor.perform_ethical_assessment(&state).await.unwrap();
+        assert!(report.pas_breakdown.contains_key("medical_advice"));
+    }
+
+    #[tokio::test]
+    async fn test_component_engine_matches_sync_engine_with_same_components() {
+        let sync_engine = BasicRecursiveCognitionEngine::new()
+            .with_ethical_assessor(RulebookEthicalAssessor::new(EthicalRulebook::builtin()))
+            .with_self_validator(ConsistencySelfValidator::new());
+        let sync_state = sync_engine.execute_full_cycle(create_test_stimulus()).unwrap();
+
+        let engine = AsyncComponentEngine::new(BasicRecursiveCognitionEngine::new())
+            .with_ethical_assessor(BlockingStage::new(RulebookEthicalAssessor::new(EthicalRulebook::builtin())))
+            .with_self_validator(BlockingStage::new(ConsistencySelfValidator::new()));
+        let async_state = engine.execute_full_cycle(create_test_stimulus(), &CycleControl::new()).await.into_result().unwrap();
+
+        assert_eq!(async_state.history_log, sync_state.history_log);
+        assert_eq!(async_state.confidence_level, sync_state.confidence_level);
+        let assessment = async_state.ethical_assessment.unwrap();
+        assert_eq!(assessment.alignment_status, sync_state.ethical_assessment.unwrap().alignment_status);
+        assert_eq!(assessment.assessed_hypothesis, async_state.current_hypothesis);
+    }
+}
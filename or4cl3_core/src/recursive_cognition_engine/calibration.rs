@@ -0,0 +1,311 @@
+// or4cl3_core/src/recursive_cognition_engine/calibration.rs
+
+//! Confidence calibration for `CognitiveState::confidence_level`.
+//! Maps raw stage confidences to calibrated probabilities using calibrators
+//! fit from labelled outcomes (Platt scaling or isotonic regression), and
+//! reports calibration error metrics on held-out samples.
+
+/// A raw confidence paired with whether the corresponding state turned out correct.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CalibrationSample {
+    pub raw_confidence: f64,
+    pub outcome: bool,
+}
+
+/// Maps a raw confidence to a calibrated probability in [0.0, 1.0].
+pub trait ConfidenceCalibrator {
+    fn calibrate(&self, raw_confidence: f64) -> f64;
+
+    /// Kind and fitted parameters of the calibrator, recorded with each cycle for replay.
+    fn version(&self) -> String {
+        "unversioned".to_string()
+    }
+}
+
+/// Clamps a confidence into [0.0, 1.0]; non-finite values become 0.5 (no information).
+pub fn clamp_confidence(value: f64) -> f64 {
+    if value.is_finite() { value.clamp(0.0, 1.0) } else { 0.5 }
+}
+
+fn validate_samples(samples: &[CalibrationSample]) -> Result<(), String> {
+    if samples.is_empty() {
+        return Err("Calibration requires at least one sample".to_string());
+    }
+    if samples.iter().any(|sample| !sample.raw_confidence.is_finite()) {
+        return Err("Calibration samples must have finite raw confidences".to_string());
+    }
+    Ok(())
+}
+
+/// Platt scaling: p = 1 / (1 + exp(-(a * raw + b))).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlattCalibrator {
+    pub a: f64,
+    pub b: f64,
+}
+
+impl PlattCalibrator {
+    /// Fits `a` and `b` by Newton's method on the log loss, using Platt's smoothed
+    /// targets so that single-class or separable samples do not diverge.
+    pub fn fit(samples: &[CalibrationSample]) -> Result<Self, String> {
+        validate_samples(samples)?;
+        let positives = samples.iter().filter(|sample| sample.outcome).count() as f64;
+        let negatives = samples.len() as f64 - positives;
+        let target_positive = (positives + 1.0) / (positives + 2.0);
+        let target_negative = 1.0 / (negatives + 2.0);
+
+        let (mut a, mut b) = (1.0, 0.0);
+        for _ in 0..100 {
+            // Gradient and Hessian of the log loss with respect to (a, b).
+            let (mut g_a, mut g_b, mut h_aa, mut h_ab, mut h_bb) = (0.0, 0.0, 1e-9, 0.0, 1e-9);
+            for sample in samples {
+                let x = clamp_confidence(sample.raw_confidence);
+                let p = 1.0 / (1.0 + (-(a * x + b)).exp());
+                let t = if sample.outcome { target_positive } else { target_negative };
+                let w = (p * (1.0 - p)).max(1e-12);
+                g_a += (p - t) * x;
+                g_b += p - t;
+                h_aa += w * x * x;
+                h_ab += w * x;
+                h_bb += w;
+            }
+            let determinant = h_aa * h_bb - h_ab * h_ab;
+            if determinant.abs() < 1e-18 {
+                break;
+            }
+            let step_a = (h_bb * g_a - h_ab * g_b) / determinant;
+            let step_b = (h_aa * g_b - h_ab * g_a) / determinant;
+            a -= step_a;
+            b -= step_b;
+            if step_a.abs() < 1e-10 && step_b.abs() < 1e-10 {
+                break;
+            }
+        }
+        Ok(Self { a, b })
+    }
+}
+
+impl ConfidenceCalibrator for PlattCalibrator {
+    fn calibrate(&self, raw_confidence: f64) -> f64 {
+        let x = clamp_confidence(raw_confidence);
+        clamp_confidence(1.0 / (1.0 + (-(self.a * x + self.b)).exp()))
+    }
+
+    fn version(&self) -> String {
+        format!("platt(a={}, b={})", self.a, self.b)
+    }
+}
+
+/// Isotonic regression: a monotone non-decreasing step function fit with
+/// pool-adjacent-violators, interpolated linearly between block centres.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IsotonicCalibrator {
+    points: Vec<(f64, f64)>, // (block centre raw confidence, calibrated probability), sorted by raw confidence
+}
+
+impl IsotonicCalibrator {
+    pub fn fit(samples: &[CalibrationSample]) -> Result<Self, String> {
+        validate_samples(samples)?;
+        let mut sorted: Vec<(f64, f64)> = samples
+            .iter()
+            .map(|sample| (clamp_confidence(sample.raw_confidence), if sample.outcome { 1.0 } else { 0.0 }))
+            .collect();
+        sorted.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        // Blocks of (sum of raw, sum of outcomes, count). Samples with the same raw confidence start
+        // in one block, so the fit does not depend on their order; blocks are then merged while they
+        // violate monotonicity.
+        let mut tied: Vec<(f64, f64, f64)> = Vec::new();
+        let mut previous_raw = None;
+        for (raw, outcome) in sorted {
+            match tied.last_mut() {
+                Some(block) if previous_raw == Some(raw) => {
+                    block.0 += raw;
+                    block.1 += outcome;
+                    block.2 += 1.0;
+                }
+                _ => tied.push((raw, outcome, 1.0)),
+            }
+            previous_raw = Some(raw);
+        }
+        let mut blocks: Vec<(f64, f64, f64)> = Vec::new();
+        for block in tied {
+            blocks.push(block);
+            while blocks.len() > 1 {
+                let last = blocks[blocks.len() - 1];
+                let previous = blocks[blocks.len() - 2];
+                if previous.1 / previous.2 <= last.1 / last.2 {
+                    break;
+                }
+                blocks.pop();
+                let merged = blocks.last_mut().expect("at least one block remains");
+                merged.0 += last.0;
+                merged.1 += last.1;
+                merged.2 += last.2;
+            }
+        }
+
+        let points = blocks.into_iter().map(|(raw_sum, outcome_sum, count)| (raw_sum / count, outcome_sum / count)).collect();
+        Ok(Self { points })
+    }
+}
+
+impl ConfidenceCalibrator for IsotonicCalibrator {
+    fn calibrate(&self, raw_confidence: f64) -> f64 {
+        let x = clamp_confidence(raw_confidence);
+        let (first, last) = match (self.points.first(), self.points.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return x,
+        };
+        if x <= first.0 {
+            return first.1;
+        }
+        if x >= last.0 {
+            return last.1;
+        }
+        let upper = self.points.iter().position(|point| point.0 >= x).unwrap_or(self.points.len() - 1);
+        let (x0, y0) = self.points[upper - 1];
+        let (x1, y1) = self.points[upper];
+        if x1 == x0 {
+            return clamp_confidence(y1);
+        }
+        clamp_confidence(y0 + (y1 - y0) * (x - x0) / (x1 - x0))
+    }
+
+    fn version(&self) -> String {
+        let points: Vec<String> = self.points.iter().map(|(raw, calibrated)| format!("{}:{}", raw, calibrated)).collect();
+        format!("isotonic({})", points.join(", "))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CalibrationReport {
+    pub sample_count: usize,
+    pub expected_calibration_error: f64, // Bin-weighted |accuracy - mean confidence|
+    pub brier_score: f64,
+    pub log_loss: f64,
+}
+
+/// Evaluates a calibrator on held-out samples using `bins` equal-width confidence bins for ECE.
+pub fn evaluate_calibration(
+    calibrator: &dyn ConfidenceCalibrator,
+    held_out: &[CalibrationSample],
+    bins: usize,
+) -> Result<CalibrationReport, String> {
+    validate_samples(held_out)?;
+    if bins == 0 {
+        return Err("Calibration evaluation requires at least one bin".to_string());
+    }
+
+    let mut bin_totals = vec![(0.0, 0.0, 0usize); bins]; // (sum of confidence, sum of outcomes, count)
+    let (mut brier, mut log_loss) = (0.0, 0.0);
+    for sample in held_out {
+        let p = calibrator.calibrate(sample.raw_confidence);
+        let y = if sample.outcome { 1.0 } else { 0.0 };
+        brier += (p - y) * (p - y);
+        let p_clipped = p.clamp(1e-12, 1.0 - 1e-12);
+        log_loss -= y * p_clipped.ln() + (1.0 - y) * (1.0 - p_clipped).ln();
+
+        let bin = ((p * bins as f64) as usize).min(bins - 1);
+        bin_totals[bin].0 += p;
+        bin_totals[bin].1 += y;
+        bin_totals[bin].2 += 1;
+    }
+
+    let n = held_out.len() as f64;
+    let expected_calibration_error = bin_totals
+        .iter()
+        .filter(|(_, _, count)| *count > 0)
+        .map(|(confidence_sum, outcome_sum, count)| {
+            let count = *count as f64;
+            (count / n) * (outcome_sum / count - confidence_sum / count).abs()
+        })
+        .sum();
+
+    Ok(CalibrationReport {
+        sample_count: held_out.len(),
+        expected_calibration_error,
+        brier_score: brier / n,
+        log_loss: log_loss / n,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Overconfident engine: raw confidence 0.9 is right 60% of the time, raw 0.6 only 20%.
+    fn create_overconfident_samples() -> Vec<CalibrationSample> {
+        let mut samples = Vec::new();
+        for i in 0..10 {
+            samples.push(CalibrationSample { raw_confidence: 0.9, outcome: i < 6 });
+            samples.push(CalibrationSample { raw_confidence: 0.6, outcome: i < 2 });
+        }
+        samples
+    }
+
+    struct Identity;
+    impl ConfidenceCalibrator for Identity {
+        fn calibrate(&self, raw_confidence: f64) -> f64 {
+            clamp_confidence(raw_confidence)
+        }
+    }
+
+    #[test]
+    fn test_isotonic_fit_is_monotone_and_matches_frequencies() {
+        let calibrator = IsotonicCalibrator::fit(&create_overconfident_samples()).unwrap();
+        assert!((calibrator.calibrate(0.9) - 0.6).abs() < 1e-9);
+        assert!((calibrator.calibrate(0.6) - 0.2).abs() < 1e-9);
+        assert!(calibrator.calibrate(0.75) > 0.2 && calibrator.calibrate(0.75) < 0.6);
+        assert_eq!(calibrator.calibrate(5.0), calibrator.calibrate(1.0));
+    }
+
+    #[test]
+    fn test_isotonic_fit_pools_tied_samples_regardless_of_order() {
+        let sample = |raw_confidence: f64, outcome: bool| CalibrationSample { raw_confidence, outcome };
+        let mut samples = vec![sample(0.3, false), sample(0.7, false), sample(0.7, true), sample(0.7, true), sample(0.9, true)];
+        let expected = IsotonicCalibrator::fit(&samples).unwrap();
+        assert!((expected.calibrate(0.7) - 2.0 / 3.0).abs() < 1e-9);
+        for _ in 0..samples.len() {
+            samples.rotate_left(1);
+            let calibrator = IsotonicCalibrator::fit(&samples).unwrap();
+            assert_eq!(calibrator, expected);
+        }
+
+        let split = IsotonicCalibrator::fit(&[sample(0.5, false), sample(0.5, true)]).unwrap();
+        assert_eq!(split.calibrate(0.5), 0.5);
+        assert_eq!(split, IsotonicCalibrator::fit(&[sample(0.5, true), sample(0.5, false)]).unwrap());
+    }
+
+    #[test]
+    fn test_platt_fit_reduces_calibration_error() {
+        let samples = create_overconfident_samples();
+        let calibrator = PlattCalibrator::fit(&samples).unwrap();
+        assert!(calibrator.calibrate(0.9) > calibrator.calibrate(0.6));
+        assert!(calibrator.calibrate(0.9) < 0.75); // Pulled down from the raw 0.9
+
+        let raw = evaluate_calibration(&Identity, &samples, 10).unwrap();
+        let calibrated = evaluate_calibration(&calibrator, &samples, 10).unwrap();
+        assert!(calibrated.expected_calibration_error < raw.expected_calibration_error);
+        assert!(calibrated.brier_score < raw.brier_score);
+        assert_eq!(calibrated.sample_count, 20);
+    }
+
+    #[test]
+    fn test_calibrated_values_stay_in_unit_interval() {
+        let platt = PlattCalibrator { a: 50.0, b: -10.0 };
+        let isotonic = IsotonicCalibrator::fit(&create_overconfident_samples()).unwrap();
+        for raw in [-3.0, 0.0, 0.5, 1.0, 7.0, f64::NAN, f64::INFINITY] {
+            for calibrated in [platt.calibrate(raw), isotonic.calibrate(raw)] {
+                assert!((0.0..=1.0).contains(&calibrated));
+            }
+        }
+    }
+
+    #[test]
+    fn test_fit_and_evaluate_reject_invalid_input() {
+        assert!(PlattCalibrator::fit(&[]).is_err());
+        assert!(IsotonicCalibrator::fit(&[CalibrationSample { raw_confidence: f64::NAN, outcome: true }]).is_err());
+        assert!(evaluate_calibration(&Identity, &create_overconfident_samples(), 0).is_err());
+    }
+}
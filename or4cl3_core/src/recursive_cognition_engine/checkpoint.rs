@@ -0,0 +1,346 @@
+// or4cl3_core/src/recursive_cognition_engine/checkpoint.rs
+
+//! Checkpoint and resume of cognition cycles.
+//! A checkpoint holding the cycle's stimulus and latest `CognitiveState` is
+//! written after every stage, so a cycle interrupted by a crash can be resumed
+//! from the stage after its last checkpoint instead of starting over. Resuming
+//! requires the engine to be configured as it was when the cycle started.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+use super::{CognitionError, CognitionStage, CognitiveState, RecursiveCognitionEngine, Stimulus};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CycleCheckpoint {
+    pub cycle_id: String,
+    pub sequence: u32, // 1 after initialization, incremented per stage
+    pub stimulus: Stimulus,
+    pub last_completed_stage: CognitionStage,
+    pub state: CognitiveState,
+    #[serde(default)]
+    pub configuration: BTreeMap<String, String>, // Engine `configuration_versions` when the cycle started
+}
+
+impl CycleCheckpoint {
+    pub fn is_final(&self) -> bool {
+        self.last_completed_stage.next().is_none()
+    }
+
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string(self).map_err(|e| format!("Failed to serialize checkpoint {}: {}", self.cycle_id, e))
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| format!("Failed to deserialize checkpoint: {}", e))
+    }
+}
+
+/// Persistence for cycle checkpoints. Only the latest checkpoint per cycle needs to be kept.
+pub trait CheckpointStore {
+    fn save(&self, checkpoint: &CycleCheckpoint) -> Result<(), String>;
+    fn load_latest(&self, cycle_id: &str) -> Result<Option<CycleCheckpoint>, String>;
+    fn clear(&self, cycle_id: &str) -> Result<(), String>;
+}
+
+/// Keeps serialized checkpoints in memory. Useful for tests and single-process runs.
+pub struct InMemoryCheckpointStore {
+    checkpoints: Mutex<HashMap<String, String>>, // cycle_id -> checkpoint JSON
+}
+
+impl InMemoryCheckpointStore {
+    pub fn new() -> Self {
+        Self { checkpoints: Mutex::new(HashMap::new()) }
+    }
+}
+
+impl Default for InMemoryCheckpointStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CheckpointStore for InMemoryCheckpointStore {
+    fn save(&self, checkpoint: &CycleCheckpoint) -> Result<(), String> {
+        let json = checkpoint.to_json()?;
+        let mut checkpoints = self.checkpoints.lock().map_err(|_| "Checkpoint store lock poisoned".to_string())?;
+        checkpoints.insert(checkpoint.cycle_id.clone(), json);
+        Ok(())
+    }
+
+    fn load_latest(&self, cycle_id: &str) -> Result<Option<CycleCheckpoint>, String> {
+        let checkpoints = self.checkpoints.lock().map_err(|_| "Checkpoint store lock poisoned".to_string())?;
+        checkpoints.get(cycle_id).map(|json| CycleCheckpoint::from_json(json)).transpose()
+    }
+
+    fn clear(&self, cycle_id: &str) -> Result<(), String> {
+        let mut checkpoints = self.checkpoints.lock().map_err(|_| "Checkpoint store lock poisoned".to_string())?;
+        checkpoints.remove(cycle_id);
+        Ok(())
+    }
+}
+
+/// Writes one JSON file per cycle (`<directory>/<cycle_id>.json`).
+/// Files are written to a temporary name, synced and renamed, so a crash mid-write keeps the previous checkpoint.
+pub struct FileCheckpointStore {
+    directory: PathBuf,
+}
+
+impl FileCheckpointStore {
+    pub fn new(directory: impl Into<PathBuf>) -> Result<Self, String> {
+        let directory = directory.into();
+        fs::create_dir_all(&directory)
+            .map_err(|e| format!("Failed to create checkpoint directory {}: {}", directory.display(), e))?;
+        Ok(Self { directory })
+    }
+
+    fn path_for(&self, cycle_id: &str) -> Result<PathBuf, String> {
+        let valid = !cycle_id.is_empty()
+            && cycle_id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !valid {
+            return Err(format!("Cycle ID '{}' must be non-empty and contain only [A-Za-z0-9_-]", cycle_id));
+        }
+        Ok(self.directory.join(format!("{}.json", cycle_id)))
+    }
+}
+
+impl CheckpointStore for FileCheckpointStore {
+    fn save(&self, checkpoint: &CycleCheckpoint) -> Result<(), String> {
+        let path = self.path_for(&checkpoint.cycle_id)?;
+        let temp_path = path.with_extension("json.tmp");
+        let json = checkpoint.to_json()?;
+        let mut file =
+            File::create(&temp_path).map_err(|e| format!("Failed to write checkpoint {}: {}", temp_path.display(), e))?;
+        file.write_all(json.as_bytes())
+            .and_then(|_| file.sync_all())
+            .map_err(|e| format!("Failed to write checkpoint {}: {}", temp_path.display(), e))?;
+        fs::rename(&temp_path, &path).map_err(|e| format!("Failed to commit checkpoint {}: {}", path.display(), e))
+    }
+
+    fn load_latest(&self, cycle_id: &str) -> Result<Option<CycleCheckpoint>, String> {
+        let path = self.path_for(cycle_id)?;
+        if !path.exists() {
+            return Ok(None);
+        }
+        let json = fs::read_to_string(&path).map_err(|e| format!("Failed to read checkpoint {}: {}", path.display(), e))?;
+        CycleCheckpoint::from_json(&json).map(Some)
+    }
+
+    fn clear(&self, cycle_id: &str) -> Result<(), String> {
+        let path = self.path_for(cycle_id)?;
+        match fs::remove_file(&path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                Err(format!("Failed to remove checkpoint {}: {}", path.display(), e))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+fn store_failure(stage: Option<CognitionStage>, message: String) -> CognitionError {
+    CognitionError::component(stage, "checkpoint_store", message)
+}
+
+fn run_remaining_stages<E: RecursiveCognitionEngine + ?Sized>(
+    engine: &E,
+    mut checkpoint: CycleCheckpoint,
+    store: &dyn CheckpointStore,
+) -> Result<CognitiveState, CognitionError> {
+    while let Some(stage) = checkpoint.last_completed_stage.next() {
+        checkpoint.state = engine.run_stage(stage, &checkpoint.state)?;
+        checkpoint.last_completed_stage = stage;
+        checkpoint.sequence += 1;
+        store.save(&checkpoint).map_err(|e| store_failure(Some(stage), e).for_state(&checkpoint.state.state_id))?;
+    }
+    Ok(checkpoint.state)
+}
+
+/// Runs a full cycle, writing a checkpoint after every stage.
+/// Fails if the store already holds a checkpoint for `cycle_id`; use `resume_cycle` for that.
+pub fn execute_with_checkpoints<E: RecursiveCognitionEngine + ?Sized>(
+    engine: &E,
+    cycle_id: &str,
+    stimulus: Stimulus,
+    store: &dyn CheckpointStore,
+) -> Result<CognitiveState, CognitionError> {
+    if store.load_latest(cycle_id).map_err(|e| store_failure(None, e))?.is_some() {
+        return Err(CognitionError::invalid_input(
+            None,
+            format!("Cycle {} already has a checkpoint; resume it instead", cycle_id),
+        ));
+    }
+    let state = engine.initialize_state_from_stimulus(stimulus.clone())?;
+    let checkpoint = CycleCheckpoint {
+        cycle_id: cycle_id.to_string(),
+        sequence: 1,
+        stimulus,
+        last_completed_stage: CognitionStage::Initialization,
+        state,
+        configuration: engine.configuration_versions(),
+    };
+    store
+        .save(&checkpoint)
+        .map_err(|e| store_failure(Some(CognitionStage::Initialization), e).for_state(&checkpoint.state.state_id))?;
+    run_remaining_stages(engine, checkpoint, store)
+}
+
+/// Continues a cycle from its latest checkpoint, running only the stages after it.
+/// A cycle whose final stage was already checkpointed returns the stored final state.
+/// Fails if the engine's configuration differs from the one the cycle started with.
+/// Finalization side effects are idempotent, so re-running an interrupted finalization
+/// does not queue the same state for review twice.
+pub fn resume_cycle<E: RecursiveCognitionEngine + ?Sized>(
+    engine: &E,
+    cycle_id: &str,
+    store: &dyn CheckpointStore,
+) -> Result<CognitiveState, CognitionError> {
+    let checkpoint = store
+        .load_latest(cycle_id)
+        .map_err(|e| store_failure(None, e))?
+        .ok_or_else(|| CognitionError::invalid_input(None, format!("No checkpoint found for cycle {}", cycle_id)))?;
+    let configuration = engine.configuration_versions();
+    if checkpoint.configuration != configuration {
+        let mut changed: Vec<&String> = checkpoint.configuration.keys().chain(configuration.keys()).collect();
+        changed.sort();
+        changed.dedup();
+        changed.retain(|key| checkpoint.configuration.get(*key) != configuration.get(*key));
+        let changed: Vec<&str> = changed.into_iter().map(String::as_str).collect();
+        return Err(CognitionError::invalid_input(
+            None,
+            format!("Cycle {} was checkpointed under a different engine configuration ({})", cycle_id, changed.join(", ")),
+        )
+        .for_state(&checkpoint.state.state_id));
+    }
+    run_remaining_stages(engine, checkpoint, store)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use crate::recursive_cognition_engine::test_engine::DelegatingTestEngine;
+    use crate::recursive_cognition_engine::{BasicRecursiveCognitionEngine, StimulusContent};
+
+    fn create_test_stimulus() -> Stimulus {
+        Stimulus {
+            id: "checkpoint_stimulus".to_string(),
+            content: StimulusContent::Text("Checkpoint me.".to_string()),
+            metadata: HashMap::from([("source".to_string(), "test".to_string())]),
+        }
+    }
+
+    /// Basic engine whose refinement fails once, simulating a crash mid-cycle.
+    fn crash_once_engine() -> DelegatingTestEngine<BasicRecursiveCognitionEngine> {
+        let crashed = AtomicBool::new(false);
+        DelegatingTestEngine::new(BasicRecursiveCognitionEngine::new()).on_stage(CognitionStage::Refinement, move |inner, state| {
+            if !crashed.swap(true, Ordering::SeqCst) {
+                return Err(CognitionError::dependency(Some(CognitionStage::Refinement), "process", "crashed", true));
+            }
+            inner.refine_cognition(state)
+        })
+    }
+
+    #[test]
+    fn test_resume_after_crash_matches_uninterrupted_cycle() {
+        let store = InMemoryCheckpointStore::new();
+        let engine = crash_once_engine();
+
+        assert!(execute_with_checkpoints(&engine, "cycle_1", create_test_stimulus(), &store).is_err());
+        let checkpoint = store.load_latest("cycle_1").unwrap().unwrap();
+        assert_eq!(checkpoint.last_completed_stage, CognitionStage::EthicalAssessment);
+        assert_eq!(checkpoint.sequence, 2);
+
+        let resumed = resume_cycle(&engine, "cycle_1", &store).unwrap();
+        let uninterrupted = BasicRecursiveCognitionEngine::new().execute_full_cycle(create_test_stimulus()).unwrap();
+        assert_eq!(resumed.history_log, uninterrupted.history_log);
+        assert_eq!(resumed.confidence_level, uninterrupted.confidence_level);
+        assert!(store.load_latest("cycle_1").unwrap().unwrap().is_final());
+    }
+
+    #[test]
+    fn test_checkpointed_cycle_rules() {
+        let store = InMemoryCheckpointStore::new();
+        let engine = BasicRecursiveCognitionEngine::new();
+        let state = execute_with_checkpoints(&engine, "cycle_2", create_test_stimulus(), &store).unwrap();
+
+        // A finished cycle resumes to its stored final state without re-running stages.
+        let resumed = resume_cycle(&engine, "cycle_2", &store).unwrap();
+        assert_eq!(resumed.history_log, state.history_log);
+        assert!(execute_with_checkpoints(&engine, "cycle_2", create_test_stimulus(), &store).is_err());
+        assert!(resume_cycle(&engine, "unknown_cycle", &store).is_err());
+
+        store.clear("cycle_2").unwrap();
+        assert!(store.load_latest("cycle_2").unwrap().is_none());
+    }
+
+    /// Fails to save the final checkpoint once, simulating a crash right after finalization.
+    struct LoseFinalCheckpointOnce {
+        inner: InMemoryCheckpointStore,
+        lost: AtomicBool,
+    }
+
+    impl CheckpointStore for LoseFinalCheckpointOnce {
+        fn save(&self, checkpoint: &CycleCheckpoint) -> Result<(), String> {
+            if checkpoint.is_final() && !self.lost.swap(true, Ordering::SeqCst) {
+                return Err("crashed".to_string());
+            }
+            self.inner.save(checkpoint)
+        }
+        fn load_latest(&self, cycle_id: &str) -> Result<Option<CycleCheckpoint>, String> { self.inner.load_latest(cycle_id) }
+        fn clear(&self, cycle_id: &str) -> Result<(), String> { self.inner.clear(cycle_id) }
+    }
+
+    #[test]
+    fn test_resume_checks_configuration_and_rerun_finalization_queues_once() {
+        use std::sync::Arc;
+
+        use crate::polyethical_manifold::{EthicalRulebook, RulebookEthicalAssessor};
+        use crate::recursive_cognition_engine::feedback::ReviewQueue;
+        use crate::recursive_cognition_engine::PasThresholds;
+
+        let queue = Arc::new(ReviewQueue::new());
+        let engine = BasicRecursiveCognitionEngine::new()
+            .with_ethical_assessor(RulebookEthicalAssessor::new(EthicalRulebook::builtin()))
+            .with_review_queue(queue.clone());
+        let stimulus = Stimulus {
+            id: "checkpoint_review".to_string(),
+            content: StimulusContent::Text("Summarize surveillance laws".to_string()), // Requires Review
+            metadata: HashMap::from([("source".to_string(), "test".to_string())]),
+        };
+        let store = LoseFinalCheckpointOnce { inner: InMemoryCheckpointStore::new(), lost: AtomicBool::new(false) };
+        assert!(execute_with_checkpoints(&engine, "cycle_3", stimulus, &store).is_err());
+        assert_eq!(queue.pending_count(), 1);
+
+        let reconfigured = BasicRecursiveCognitionEngine::new()
+            .with_ethical_assessor(RulebookEthicalAssessor::new(EthicalRulebook::builtin()))
+            .with_pas_thresholds(PasThresholds::new(0.8, 0.5).unwrap());
+        let err = resume_cycle(&reconfigured, "cycle_3", &store).unwrap_err();
+        assert!(err.to_string().contains("different engine configuration (pas_thresholds)"));
+
+        resume_cycle(&engine, "cycle_3", &store).unwrap();
+        assert_eq!(queue.pending_count(), 1); // Finalization ran twice, the state was queued once
+    }
+
+    #[test]
+    fn test_file_store_round_trip() {
+        let directory = std::env::temp_dir().join(format!("or4cl3_checkpoints_{}", std::process::id()));
+        let store = FileCheckpointStore::new(&directory).unwrap();
+        let engine = BasicRecursiveCognitionEngine::new();
+
+        let state = execute_with_checkpoints(&engine, "file_cycle", create_test_stimulus(), &store).unwrap();
+        let loaded = store.load_latest("file_cycle").unwrap().unwrap();
+        assert_eq!(loaded.state.history_log, state.history_log);
+        assert_eq!(loaded.stimulus.metadata.get("source").map(String::as_str), Some("test"));
+        assert!(store.save(&CycleCheckpoint { cycle_id: "../escape".to_string(), ..loaded }).is_err());
+
+        store.clear("file_cycle").unwrap();
+        store.clear("file_cycle").unwrap(); // Clearing twice is fine
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
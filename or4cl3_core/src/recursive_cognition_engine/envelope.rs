@@ -0,0 +1,320 @@
+// or4cl3_core/src/recursive_cognition_engine/envelope.rs
+
+//! Typed stimulus envelope.
+//! `Stimulus.metadata` stays a string map so that rulebook conditions and other
+//! components can read it, but its well-known keys follow the schema below.
+//! `StimulusEnvelope` parses and validates those keys, reporting every problem
+//! at once, and writes them back in canonical form.
+//!
+//! | key           | required | value                                                  |
+//! |---------------|----------|--------------------------------------------------------|
+//! | `source`      | yes      | non-empty provenance description                       |
+//! | `timestamp`   | yes      | Unix seconds                                           |
+//! | `user_id`     | yes      | non-empty                                              |
+//! | `locale`      | yes      | language tag, e.g. `en` or `pt-BR`                     |
+//! | `sensitivity` | yes      | `public`, `internal`, `confidential` or `restricted`   |
+//! | `consent`     | no       | comma-separated scopes; absent means none were granted |
+
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+
+use super::{Stimulus, StimulusContent};
+
+pub const SOURCE_KEY: &str = "source";
+pub const TIMESTAMP_KEY: &str = "timestamp";
+pub const USER_ID_KEY: &str = "user_id";
+pub const LOCALE_KEY: &str = "locale";
+pub const SENSITIVITY_KEY: &str = "sensitivity";
+pub const CONSENT_KEY: &str = "consent";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Sensitivity {
+    Public,
+    Internal,
+    Confidential,
+    Restricted,
+}
+
+impl Sensitivity {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_lowercase().as_str() {
+            "public" => Ok(Sensitivity::Public),
+            "internal" => Ok(Sensitivity::Internal),
+            "confidential" => Ok(Sensitivity::Confidential),
+            "restricted" => Ok(Sensitivity::Restricted),
+            other => Err(format!("unknown sensitivity '{}'", other)),
+        }
+    }
+}
+
+impl fmt::Display for Sensitivity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Sensitivity::Public => write!(f, "public"),
+            Sensitivity::Internal => write!(f, "internal"),
+            Sensitivity::Confidential => write!(f, "confidential"),
+            Sensitivity::Restricted => write!(f, "restricted"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ConsentScope {
+    DataProcessing,
+    PersonalData,
+    Research,
+}
+
+impl ConsentScope {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_lowercase().as_str() {
+            "data_processing" => Ok(ConsentScope::DataProcessing),
+            "personal_data" => Ok(ConsentScope::PersonalData),
+            "research" => Ok(ConsentScope::Research),
+            other => Err(format!("unknown consent scope '{}'", other)),
+        }
+    }
+}
+
+impl fmt::Display for ConsentScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConsentScope::DataProcessing => write!(f, "data_processing"),
+            ConsentScope::PersonalData => write!(f, "personal_data"),
+            ConsentScope::Research => write!(f, "research"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StimulusProvenance {
+    pub source: String,
+    pub timestamp: u64, // Unix seconds
+    pub user_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EnvelopeError {
+    Missing { key: String },
+    Invalid { key: String, value: String, reason: String },
+}
+
+impl fmt::Display for EnvelopeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EnvelopeError::Missing { key } => write!(f, "missing required metadata '{}'", key),
+            EnvelopeError::Invalid { key, value, reason } => write!(f, "invalid metadata '{}' = '{}': {}", key, value, reason),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StimulusEnvelope {
+    pub provenance: StimulusProvenance,
+    pub locale: String, // Canonical language tag: lowercase language, uppercase region
+    pub consent: BTreeSet<ConsentScope>,
+    pub sensitivity: Sensitivity,
+    pub extra: HashMap<String, String>, // Metadata outside the schema, passed through unchanged
+}
+
+impl StimulusEnvelope {
+    pub fn new(provenance: StimulusProvenance, locale: &str, sensitivity: Sensitivity) -> Result<Self, EnvelopeError> {
+        let locale = canonical_locale(locale).map_err(|reason| EnvelopeError::Invalid {
+            key: LOCALE_KEY.to_string(),
+            value: locale.to_string(),
+            reason,
+        })?;
+        Ok(Self { provenance, locale, consent: BTreeSet::new(), sensitivity, extra: HashMap::new() })
+    }
+
+    pub fn with_consent(mut self, scope: ConsentScope) -> Self {
+        self.consent.insert(scope);
+        self
+    }
+
+    pub fn with_extra(mut self, key: &str, value: &str) -> Self {
+        self.extra.insert(key.to_string(), value.to_string());
+        self
+    }
+
+    pub fn has_consent(&self, scope: ConsentScope) -> bool {
+        self.consent.contains(&scope)
+    }
+
+    /// Parses and validates the schema keys of a metadata map, returning every problem found.
+    pub fn from_metadata(metadata: &HashMap<String, String>) -> Result<Self, Vec<EnvelopeError>> {
+        let mut errors = Vec::new();
+        let mut required = |key: &str| -> Option<String> {
+            match metadata.get(key).map(|value| value.trim()) {
+                Some(value) if !value.is_empty() => Some(value.to_string()),
+                _ => {
+                    errors.push(EnvelopeError::Missing { key: key.to_string() });
+                    None
+                }
+            }
+        };
+        let source = required(SOURCE_KEY);
+        let timestamp = required(TIMESTAMP_KEY);
+        let user_id = required(USER_ID_KEY);
+        let locale = required(LOCALE_KEY);
+        let sensitivity = required(SENSITIVITY_KEY);
+
+        let invalid = |key: &str, value: &str, reason: String| EnvelopeError::Invalid {
+            key: key.to_string(),
+            value: value.to_string(),
+            reason,
+        };
+        let timestamp = timestamp.and_then(|value| match value.parse::<u64>() {
+            Ok(timestamp) => Some(timestamp),
+            Err(_) => {
+                errors.push(invalid(TIMESTAMP_KEY, &value, "expected Unix seconds".to_string()));
+                None
+            }
+        });
+        let locale = locale.and_then(|value| match canonical_locale(&value) {
+            Ok(locale) => Some(locale),
+            Err(reason) => {
+                errors.push(invalid(LOCALE_KEY, &value, reason));
+                None
+            }
+        });
+        let sensitivity = sensitivity.and_then(|value| match Sensitivity::parse(&value) {
+            Ok(sensitivity) => Some(sensitivity),
+            Err(reason) => {
+                errors.push(invalid(SENSITIVITY_KEY, &value, reason));
+                None
+            }
+        });
+        let mut consent = BTreeSet::new();
+        if let Some(value) = metadata.get(CONSENT_KEY) {
+            for scope in value.split(',').map(str::trim).filter(|scope| !scope.is_empty()) {
+                match ConsentScope::parse(scope) {
+                    Ok(scope) => {
+                        consent.insert(scope);
+                    }
+                    Err(reason) => errors.push(invalid(CONSENT_KEY, value, reason)),
+                }
+            }
+        }
+
+        match (source, timestamp, user_id, locale, sensitivity) {
+            (Some(source), Some(timestamp), Some(user_id), Some(locale), Some(sensitivity)) if errors.is_empty() => {
+                let schema_keys = [SOURCE_KEY, TIMESTAMP_KEY, USER_ID_KEY, LOCALE_KEY, SENSITIVITY_KEY, CONSENT_KEY];
+                let extra = metadata
+                    .iter()
+                    .filter(|(key, _)| !schema_keys.contains(&key.as_str()))
+                    .map(|(key, value)| (key.clone(), value.clone()))
+                    .collect();
+                Ok(Self { provenance: StimulusProvenance { source, timestamp, user_id }, locale, consent, sensitivity, extra })
+            }
+            _ => Err(errors),
+        }
+    }
+
+    /// Writes the envelope back as metadata, with schema values in canonical form.
+    pub fn to_metadata(&self) -> HashMap<String, String> {
+        let mut metadata = self.extra.clone();
+        metadata.insert(SOURCE_KEY.to_string(), self.provenance.source.clone());
+        metadata.insert(TIMESTAMP_KEY.to_string(), self.provenance.timestamp.to_string());
+        metadata.insert(USER_ID_KEY.to_string(), self.provenance.user_id.clone());
+        metadata.insert(LOCALE_KEY.to_string(), self.locale.clone());
+        metadata.insert(SENSITIVITY_KEY.to_string(), self.sensitivity.to_string());
+        if !self.consent.is_empty() {
+            let scopes: Vec<String> = self.consent.iter().map(ToString::to_string).collect();
+            metadata.insert(CONSENT_KEY.to_string(), scopes.join(","));
+        }
+        metadata
+    }
+
+    pub fn into_stimulus(self, id: &str, content: StimulusContent) -> Stimulus {
+        Stimulus { id: id.to_string(), content, metadata: self.to_metadata() }
+    }
+}
+
+/// Accepts `ll`, `lll`, `ll-RR` or `ll-999` (either separator, any case) and returns e.g. `pt-BR`.
+pub fn canonical_locale(value: &str) -> Result<String, String> {
+    let mut parts = value.trim().split(['-', '_']);
+    let language = parts.next().unwrap_or_default();
+    let region = parts.next();
+    if parts.next().is_some() {
+        return Err("expected a language with an optional region, e.g. 'en' or 'pt-BR'".to_string());
+    }
+    if !(2..=3).contains(&language.len()) || !language.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err("language must be 2 or 3 letters".to_string());
+    }
+    let language = language.to_ascii_lowercase();
+    match region {
+        None => Ok(language),
+        Some(region) if region.len() == 2 && region.chars().all(|c| c.is_ascii_alphabetic()) => {
+            Ok(format!("{}-{}", language, region.to_ascii_uppercase()))
+        }
+        Some(region) if region.len() == 3 && region.chars().all(|c| c.is_ascii_digit()) => Ok(format!("{}-{}", language, region)),
+        Some(_) => Err("region must be 2 letters or 3 digits".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_metadata(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn test_valid_metadata_parses_and_round_trips() {
+        let metadata = create_metadata(&[
+            ("source", "web_chat"),
+            ("timestamp", "1700000000"),
+            ("user_id", "user_42"),
+            ("locale", "pt_br"),
+            ("sensitivity", "Confidential"),
+            ("consent", "personal_data, research"),
+            ("context_tags", "urban_surveillance"),
+        ]);
+        let envelope = StimulusEnvelope::from_metadata(&metadata).unwrap();
+        assert_eq!(envelope.locale, "pt-BR");
+        assert_eq!(envelope.sensitivity, Sensitivity::Confidential);
+        assert!(envelope.has_consent(ConsentScope::PersonalData));
+        assert!(!envelope.has_consent(ConsentScope::DataProcessing));
+        assert_eq!(envelope.extra.get("context_tags").map(String::as_str), Some("urban_surveillance"));
+
+        let canonical = envelope.to_metadata();
+        assert_eq!(canonical.get("consent").map(String::as_str), Some("personal_data,research"));
+        assert_eq!(canonical.get("sensitivity").map(String::as_str), Some("confidential"));
+        assert_eq!(StimulusEnvelope::from_metadata(&canonical).unwrap(), envelope);
+    }
+
+    #[test]
+    fn test_invalid_metadata_reports_every_problem() {
+        let metadata = create_metadata(&[
+            ("source", "web_chat"),
+            ("timestamp", "yesterday"),
+            ("locale", "english"),
+            ("sensitivity", "secret"),
+            ("consent", "marketing"),
+        ]);
+        let errors = StimulusEnvelope::from_metadata(&metadata).unwrap_err();
+        assert_eq!(errors.len(), 5);
+        assert!(errors.contains(&EnvelopeError::Missing { key: "user_id".to_string() }));
+        let invalid_keys: Vec<&str> = errors
+            .iter()
+            .filter_map(|error| match error {
+                EnvelopeError::Invalid { key, .. } => Some(key.as_str()),
+                EnvelopeError::Missing { .. } => None,
+            })
+            .collect();
+        assert_eq!(invalid_keys, vec!["timestamp", "locale", "sensitivity", "consent"]);
+    }
+
+    #[test]
+    fn test_builder_produces_stimulus_without_consent_key() {
+        let provenance = StimulusProvenance { source: "api".to_string(), timestamp: 1, user_id: "u".to_string() };
+        let stimulus = StimulusEnvelope::new(provenance, "EN", Sensitivity::Public)
+            .unwrap()
+            .into_stimulus("enveloped", StimulusContent::Text("Hello".to_string()));
+        assert_eq!(stimulus.metadata.get("locale").map(String::as_str), Some("en"));
+        assert!(!stimulus.metadata.contains_key("consent")); // Rulebook `consent_missing:personal_data` still applies
+        assert!(canonical_locale("en-US-x").is_err());
+    }
+}
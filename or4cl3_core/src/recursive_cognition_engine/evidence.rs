@@ -0,0 +1,293 @@
+// or4cl3_core/src/recursive_cognition_engine/evidence.rs
+
+//! Evidence retrieval for the refinement stage.
+//! Queries the Mythos Memory Core for historical claims relevant to a cognitive
+//! state, attaches their IDs as supporting evidence, and weights the state's
+//! confidence by how well that evidence validates.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::learning::{read_parameter, SharedParameters, EVIDENCE_WEIGHT_OFFSET};
+use super::{CognitiveState, RefinementEngine};
+use crate::mythos_memory_core::{HistoricalClaim, MythosIntegrityGuard, MythosKnowledgeGraph, ValidationScore};
+
+/// History entry logged when no claim in the knowledge graph is relevant to the state.
+pub const NO_RELEVANT_EVIDENCE: &str = "Evidence retrieval found no relevant Mythos claims";
+
+/// What evidence retrieval found for a state, kept on it as `CognitiveState::evidence_outcome`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EvidenceOutcome {
+    NoRelevantClaims,
+    Attached { claims: usize }, // Claims retrieved by this refinement
+}
+
+#[derive(Debug, Clone)]
+pub struct EvidenceRetrievalConfig {
+    pub context_tags_metadata_key: String, // Stimulus metadata key holding comma-separated context tags
+    pub max_similar_claims: usize,         // Upper bound for the content similarity query
+    pub min_similarity: f32,               // Similar claims below this score are ignored
+    pub max_evidence: usize,               // Upper bound on evidence attached per refinement
+    pub evidence_weight: f64,              // Share of the refined confidence driven by evidence (0.0 to 1.0)
+}
+
+impl Default for EvidenceRetrievalConfig {
+    fn default() -> Self {
+        Self {
+            context_tags_metadata_key: "context_tags".to_string(),
+            max_similar_claims: 5,
+            min_similarity: 0.2,
+            max_evidence: 10,
+            evidence_weight: 0.5,
+        }
+    }
+}
+
+/// A claim retrieved as evidence, with its relevance to the state and its validation result.
+#[derive(Debug, Clone)]
+pub struct RetrievedEvidence {
+    pub claim: HistoricalClaim,
+    pub relevance: f32, // 1.0 for context tag matches, the similarity score otherwise
+    pub validation: ValidationScore,
+}
+
+/// RefinementEngine that grounds a cognitive state in Mythos Memory Core evidence.
+pub struct MythosEvidenceRefiner<G: MythosKnowledgeGraph, V: MythosIntegrityGuard> {
+    graph: G,
+    integrity_guard: V,
+    config: EvidenceRetrievalConfig,
+    parameters: Option<SharedParameters>, // Learned evidence weight offset, if any
+}
+
+impl<G: MythosKnowledgeGraph, V: MythosIntegrityGuard> MythosEvidenceRefiner<G, V> {
+    pub fn new(graph: G, integrity_guard: V) -> Self {
+        Self { graph, integrity_guard, config: EvidenceRetrievalConfig::default(), parameters: None }
+    }
+
+    /// Adds the learned `refinement.evidence_weight_offset` parameter to the configured evidence weight.
+    pub fn with_parameters(mut self, parameters: SharedParameters) -> Self {
+        self.parameters = Some(parameters);
+        self
+    }
+
+    pub fn with_config(mut self, config: EvidenceRetrievalConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Looks up claims by the stimulus' context tags and by similarity to the stimulus
+    /// text, validates each one, and returns them ordered by relevance.
+    pub fn retrieve_evidence(&self, state: &CognitiveState) -> Result<Vec<RetrievedEvidence>, String> {
+        let mut candidates: HashMap<String, (HistoricalClaim, f32)> = HashMap::new();
+
+        if let Some(tags) = state.stimulus_metadata.get(&self.config.context_tags_metadata_key) {
+            for tag in tags.split(',').map(str::trim).filter(|tag| !tag.is_empty()) {
+                for claim in self.graph.get_narratives_by_context_tag(tag)? {
+                    candidates.insert(claim.claim_id.clone(), (claim, 1.0));
+                }
+            }
+        }
+
+        let query_text = if state.stimulus_text.is_empty() { &state.current_hypothesis } else { &state.stimulus_text };
+        for (claim, similarity) in self.graph.find_similar_narratives(query_text, self.config.max_similar_claims)? {
+            if similarity < self.config.min_similarity {
+                continue;
+            }
+            let entry = candidates.entry(claim.claim_id.clone()).or_insert((claim, similarity));
+            entry.1 = entry.1.max(similarity);
+        }
+
+        let mut evidence = candidates
+            .into_values()
+            .map(|(claim, relevance)| {
+                let validation = self.integrity_guard.validate_historical_claim(&claim)?;
+                Ok(RetrievedEvidence { claim, relevance, validation })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        evidence.sort_by(|a, b| {
+            b.relevance.total_cmp(&a.relevance).then_with(|| a.claim.claim_id.cmp(&b.claim.claim_id))
+        });
+        evidence.truncate(self.config.max_evidence);
+        Ok(evidence)
+    }
+}
+
+impl<G: MythosKnowledgeGraph, V: MythosIntegrityGuard> RefinementEngine for MythosEvidenceRefiner<G, V> {
+    fn refine_cognitive_state(&self, state: &CognitiveState) -> Result<CognitiveState, String> {
+        let evidence = self.retrieve_evidence(state)?;
+        let mut new_state = state.clone();
+
+        if evidence.is_empty() {
+            new_state.evidence_outcome = Some(EvidenceOutcome::NoRelevantClaims);
+            new_state.history_log.push(NO_RELEVANT_EVIDENCE.to_string());
+            return Ok(new_state);
+        }
+        new_state.evidence_outcome = Some(EvidenceOutcome::Attached { claims: evidence.len() });
+
+        for item in &evidence {
+            if !new_state.supporting_evidence_ids.contains(&item.claim.claim_id) {
+                new_state.supporting_evidence_ids.push(item.claim.claim_id.clone());
+            }
+        }
+
+        // Relevance-weighted mean of the evidence validation scores; a plain mean
+        // when no item carries any relevance.
+        let total_relevance: f64 = evidence.iter().map(|item| item.relevance as f64).sum();
+        let evidence_score = if total_relevance > 0.0 {
+            evidence
+                .iter()
+                .map(|item| item.relevance as f64 * item.validation.overall_score as f64)
+                .sum::<f64>()
+                / total_relevance
+        } else {
+            evidence.iter().map(|item| item.validation.overall_score as f64).sum::<f64>() / evidence.len() as f64
+        };
+
+        let offset = self
+            .parameters
+            .as_ref()
+            .map(|parameters| read_parameter(parameters, EVIDENCE_WEIGHT_OFFSET))
+            .unwrap_or(0.0);
+        let weight = (self.config.evidence_weight + offset).clamp(0.0, 1.0);
+        new_state.confidence_level =
+            (state.confidence_level * (1.0 - weight) + evidence_score * weight).clamp(0.0, 1.0);
+        new_state.history_log.push(format!(
+            "Evidence retrieval attached {} Mythos claim(s) (validation score {:.2})",
+            evidence.len(),
+            evidence_score
+        ));
+        Ok(new_state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mythos_memory_core::{BasicMythosIntegrityGuard, InMemoryMythosGraph, ProvenanceData};
+    use crate::recursive_cognition_engine::{BasicRecursiveCognitionEngine, RecursiveCognitionEngine, Stimulus, StimulusContent};
+
+    fn create_claim(claim_id: &str, narrative_content: &str, tags: &[&str], signed: bool) -> HistoricalClaim {
+        HistoricalClaim {
+            claim_id: claim_id.to_string(),
+            narrative_content: narrative_content.to_string(),
+            source_description: "Evidence test source.".to_string(),
+            cultural_context_tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            provenance: ProvenanceData {
+                document_id: format!("doc_{}", claim_id),
+                author_id: "author_evidence".to_string(),
+                timestamp: 1678886400,
+                cryptographic_signature: if signed { Some("sig".to_string()) } else { None },
+            },
+        }
+    }
+
+    fn create_graph() -> InMemoryMythosGraph {
+        let graph = InMemoryMythosGraph::new();
+        graph.add_historical_claim(&create_claim("claim_tagged", "Census records from the 1940s.", &["urban_surveillance"], true)).unwrap();
+        graph.add_historical_claim(&create_claim("claim_similar", "Redlining maps restricted housing loans.", &[], false)).unwrap();
+        graph.add_historical_claim(&create_claim("claim_unrelated", "Informed consent in medical trials.", &["medical_ethics"], true)).unwrap();
+        graph
+    }
+
+    fn create_state(text: &str, tags: Option<&str>) -> CognitiveState {
+        let mut stimulus_metadata = HashMap::new();
+        if let Some(tags) = tags {
+            stimulus_metadata.insert("context_tags".to_string(), tags.to_string());
+        }
+        CognitiveState {
+            state_id: "state_evidence".to_string(),
+            stimulus_id: "stimulus_evidence".to_string(),
+            stimulus_text: text.to_string(),
+            stimulus_metadata,
+            current_hypothesis: "Initial hypothesis".to_string(),
+            confidence_level: 0.5,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_retrieves_by_tag_and_similarity() {
+        let refiner = MythosEvidenceRefiner::new(create_graph(), BasicMythosIntegrityGuard {});
+        let state = create_state("How did redlining maps affect housing loans?", Some("urban_surveillance"));
+
+        let evidence = refiner.retrieve_evidence(&state).unwrap();
+        let ids: Vec<&str> = evidence.iter().map(|item| item.claim.claim_id.as_str()).collect();
+        assert_eq!(ids, vec!["claim_tagged", "claim_similar"]); // Tag match ranks first
+        assert_eq!(evidence[0].relevance, 1.0);
+    }
+
+    #[test]
+    fn test_refinement_attaches_evidence_and_weights_confidence() {
+        let refiner = MythosEvidenceRefiner::new(create_graph(), BasicMythosIntegrityGuard {});
+        let state = create_state("Census records", Some("urban_surveillance"));
+
+        let refined = refiner.refine_cognitive_state(&state).unwrap();
+        assert_eq!(refined.supporting_evidence_ids, vec!["claim_tagged".to_string()]);
+        // Signed claim validates at 0.825, so confidence moves halfway from 0.5 towards it.
+        assert!((refined.confidence_level - 0.6625).abs() < 1e-6);
+        assert!(refined.history_log.last().unwrap().contains("attached 1 Mythos claim"));
+        assert_eq!(refined.evidence_outcome, Some(EvidenceOutcome::Attached { claims: 1 }));
+    }
+
+    #[test]
+    fn test_refinement_without_evidence_keeps_confidence() {
+        let refiner = MythosEvidenceRefiner::new(create_graph(), BasicMythosIntegrityGuard {});
+        let state = create_state("Quantum chromodynamics", None);
+
+        let refined = refiner.refine_cognitive_state(&state).unwrap();
+        assert!(refined.supporting_evidence_ids.is_empty());
+        assert_eq!(refined.confidence_level, 0.5);
+        assert_eq!(refined.evidence_outcome, Some(EvidenceOutcome::NoRelevantClaims));
+    }
+
+    /// Returns every claim as similar, with a similarity of zero.
+    struct ZeroSimilarityGraph(InMemoryMythosGraph);
+
+    impl MythosKnowledgeGraph for ZeroSimilarityGraph {
+        fn add_historical_claim(&self, claim: &HistoricalClaim) -> Result<String, String> {
+            self.0.add_historical_claim(claim)
+        }
+        fn get_historical_claim_by_id(&self, claim_id: &str) -> Result<Option<HistoricalClaim>, String> {
+            self.0.get_historical_claim_by_id(claim_id)
+        }
+        fn get_related_narratives(&self, claim_id: &str, relationship_type: &str) -> Result<Vec<HistoricalClaim>, String> {
+            self.0.get_related_narratives(claim_id, relationship_type)
+        }
+        fn get_narratives_by_context_tag(&self, context_tag: &str) -> Result<Vec<HistoricalClaim>, String> {
+            self.0.get_narratives_by_context_tag(context_tag)
+        }
+        fn find_similar_narratives(&self, _text: &str, _limit: usize) -> Result<Vec<(HistoricalClaim, f32)>, String> {
+            let claim = self.0.get_historical_claim_by_id("claim_tagged")?.unwrap();
+            Ok(vec![(claim, 0.0)])
+        }
+    }
+
+    #[test]
+    fn test_refinement_with_zero_relevance_uses_plain_mean() {
+        let config = EvidenceRetrievalConfig { min_similarity: 0.0, ..Default::default() };
+        let refiner = MythosEvidenceRefiner::new(ZeroSimilarityGraph(create_graph()), BasicMythosIntegrityGuard {}).with_config(config);
+        let state = create_state("Census records", None);
+
+        let refined = refiner.refine_cognitive_state(&state).unwrap();
+        assert_eq!(refined.supporting_evidence_ids, vec!["claim_tagged".to_string()]);
+        assert!((refined.confidence_level - 0.6625).abs() < 1e-6); // Not NaN
+    }
+
+    #[test]
+    fn test_engine_full_cycle_uses_evidence_refiner() {
+        let refiner = MythosEvidenceRefiner::new(create_graph(), BasicMythosIntegrityGuard {});
+        let engine = BasicRecursiveCognitionEngine::new().with_refinement_engine(refiner);
+        let mut metadata = HashMap::new();
+        metadata.insert("context_tags".to_string(), "medical_ethics".to_string());
+        let stimulus = Stimulus {
+            id: "stimulus_cycle".to_string(),
+            content: StimulusContent::Text("Consent requirements".to_string()),
+            metadata,
+        };
+
+        let state = engine.execute_full_cycle(stimulus).unwrap();
+        assert_eq!(state.supporting_evidence_ids, vec!["claim_unrelated".to_string()]);
+    }
+}
@@ -0,0 +1,380 @@
+// or4cl3_core/src/recursive_cognition_engine/feedback.rs
+
+//! Human review loop for the Recursive Cognition Engine.
+//! States that require review are queued; reviewers claim pending items,
+//! submit `HumanFeedback`, and the engine resumes the cycle from the stored
+//! state with the correction applied.
+
+use std::sync::Mutex;
+
+use super::{CognitiveState, FeedbackIntegrator, HumanFeedback};
+
+/// Structured reading of `HumanFeedback::feedback_content`.
+/// Content that matches none of the known forms is kept as a free-text comment.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FeedbackVerdict {
+    Approve,                                 // "approve" or "agree"
+    Reject { reason: String },               // "reject: <reason>" or "disagree: <reason>"
+    Correct { revised_hypothesis: String },  // "correct: <revised hypothesis>"
+    Rating(u8),                              // "rating: <1-5>"
+    Comment(String),
+}
+
+impl FeedbackVerdict {
+    pub fn parse(content: &str) -> Self {
+        let trimmed = content.trim();
+        let (keyword, argument) = match trimmed.split_once(':') {
+            Some((keyword, argument)) => (keyword.trim().to_lowercase(), argument.trim()),
+            None => (trimmed.to_lowercase(), ""),
+        };
+        match keyword.as_str() {
+            "approve" | "agree" => FeedbackVerdict::Approve,
+            "reject" | "disagree" => FeedbackVerdict::Reject { reason: argument.to_string() },
+            "correct" if !argument.is_empty() => {
+                FeedbackVerdict::Correct { revised_hypothesis: argument.to_string() }
+            }
+            "rating" => match argument.parse::<u8>() {
+                Ok(rating) if (1..=5).contains(&rating) => FeedbackVerdict::Rating(rating),
+                _ => FeedbackVerdict::Comment(trimmed.to_string()),
+            },
+            _ => FeedbackVerdict::Comment(trimmed.to_string()),
+        }
+    }
+
+    /// Canonical content string; `FeedbackVerdict::parse` reads it back unchanged.
+    pub fn to_content(&self) -> String {
+        match self {
+            FeedbackVerdict::Approve => "approve".to_string(),
+            FeedbackVerdict::Reject { reason } => format!("reject: {}", reason),
+            FeedbackVerdict::Correct { revised_hypothesis } => format!("correct: {}", revised_hypothesis),
+            FeedbackVerdict::Rating(rating) => format!("rating: {}", rating),
+            FeedbackVerdict::Comment(text) => text.clone(),
+        }
+    }
+}
+
+/// FeedbackIntegrator that applies a parsed `FeedbackVerdict` to a state.
+/// A correction replaces the hypothesis and clears the ethical assessment,
+/// so the engine has to re-assess the corrected hypothesis.
+pub struct VerdictFeedbackIntegrator;
+
+impl FeedbackIntegrator for VerdictFeedbackIntegrator {
+    fn integrate_human_feedback(&self, base_state: &CognitiveState, feedback: HumanFeedback) -> Result<CognitiveState, String> {
+        let mut new_state = base_state.clone();
+        let verdict = FeedbackVerdict::parse(&feedback.feedback_content);
+        let summary = match &verdict {
+            FeedbackVerdict::Approve => {
+                new_state.confidence_level += (1.0 - new_state.confidence_level) * 0.5;
+                "approved".to_string()
+            }
+            FeedbackVerdict::Reject { reason } => {
+                new_state.confidence_level *= 0.5;
+                if let Some(assessment) = new_state.ethical_assessment.as_mut() {
+                    assessment.ethical_concerns.push(format!("Reviewer rejection: {}", reason));
+                }
+                format!("rejected ({})", reason)
+            }
+            FeedbackVerdict::Correct { revised_hypothesis } => {
+                new_state.current_hypothesis = revised_hypothesis.clone();
+                new_state.ethical_assessment = None; // Stale for the corrected hypothesis
+                "hypothesis corrected".to_string()
+            }
+            FeedbackVerdict::Rating(rating) => {
+                new_state.confidence_level = (new_state.confidence_level + *rating as f64 / 5.0) / 2.0;
+                format!("rated {}/5", rating)
+            }
+            FeedbackVerdict::Comment(text) => {
+                new_state.current_hypothesis = format!("{} (feedback incorporated: {})", base_state.current_hypothesis, text);
+                "comment incorporated".to_string()
+            }
+        };
+        new_state.confidence_level = new_state.confidence_level.clamp(0.0, 1.0);
+        new_state.history_log.push(format!(
+            "Human feedback '{}' from {} integrated: {}",
+            feedback.feedback_id, feedback.user_id, summary
+        ));
+        Ok(new_state)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReviewStatus {
+    Pending,
+    Claimed { reviewer_id: String },
+    Submitted { reviewer_id: String, feedback: HumanFeedback },
+    Resolved { reviewer_id: String },
+}
+
+#[derive(Debug, Clone)]
+pub struct ReviewItem {
+    pub review_id: String,
+    pub state: CognitiveState, // State as it was when queued; the cycle resumes from here
+    pub reason: String,
+    pub status: ReviewStatus,
+}
+
+/// Thread-safe FIFO of cognitive states awaiting human review.
+pub struct ReviewQueue {
+    items: Mutex<Vec<ReviewItem>>,
+}
+
+impl ReviewQueue {
+    pub fn new() -> Self {
+        Self { items: Mutex::new(Vec::new()) }
+    }
+
+    /// Queues a state for review and returns its review ID. A state that is already
+    /// awaiting review (pending or claimed) is not queued again; its review ID is returned.
+    pub fn enqueue(&self, state: CognitiveState, reason: &str) -> Result<String, String> {
+        let mut items = self.items.lock().map_err(|_| "Review queue lock poisoned".to_string())?;
+        let awaiting = items.iter().find(|item| {
+            item.state.state_id == state.state_id
+                && matches!(item.status, ReviewStatus::Pending | ReviewStatus::Claimed { .. })
+        });
+        if let Some(item) = awaiting {
+            return Ok(item.review_id.clone());
+        }
+        let review_id = format!("review_{}_{}", items.len() + 1, state.state_id);
+        items.push(ReviewItem {
+            review_id: review_id.clone(),
+            state,
+            reason: reason.to_string(),
+            status: ReviewStatus::Pending,
+        });
+        Ok(review_id)
+    }
+
+    /// Claims up to `limit` pending items for the reviewer, oldest first.
+    pub fn fetch_pending(&self, reviewer_id: &str, limit: usize) -> Result<Vec<ReviewItem>, String> {
+        let mut items = self.items.lock().map_err(|_| "Review queue lock poisoned".to_string())?;
+        let mut claimed = Vec::new();
+        for item in items.iter_mut().filter(|item| item.status == ReviewStatus::Pending).take(limit) {
+            item.status = ReviewStatus::Claimed { reviewer_id: reviewer_id.to_string() };
+            claimed.push(item.clone());
+        }
+        Ok(claimed)
+    }
+
+    /// Returns a claimed item to the pending pool, e.g. when its reviewer gives up on it.
+    /// Only the reviewer who claimed the item can release it.
+    pub fn release(&self, review_id: &str, reviewer_id: &str) -> Result<(), String> {
+        let mut items = self.items.lock().map_err(|_| "Review queue lock poisoned".to_string())?;
+        let item = items
+            .iter_mut()
+            .find(|item| item.review_id == review_id)
+            .ok_or_else(|| format!("Review {} not found", review_id))?;
+        match &item.status {
+            ReviewStatus::Claimed { reviewer_id: claimant } if claimant == reviewer_id => {}
+            ReviewStatus::Claimed { reviewer_id: claimant } => {
+                return Err(format!("Review {} is claimed by {}, not {}", review_id, claimant, reviewer_id));
+            }
+            other => return Err(format!("Review {} is not claimed (status: {:?})", review_id, other)),
+        }
+        item.status = ReviewStatus::Pending;
+        Ok(())
+    }
+
+    /// Records the reviewer's feedback for a claimed item.
+    /// The feedback must come from the reviewer who claimed the item.
+    pub fn submit_feedback(&self, review_id: &str, feedback: HumanFeedback) -> Result<(), String> {
+        let mut items = self.items.lock().map_err(|_| "Review queue lock poisoned".to_string())?;
+        let item = items
+            .iter_mut()
+            .find(|item| item.review_id == review_id)
+            .ok_or_else(|| format!("Review {} not found", review_id))?;
+        let reviewer_id = match &item.status {
+            ReviewStatus::Claimed { reviewer_id } => reviewer_id.clone(),
+            other => return Err(format!("Review {} is not awaiting feedback (status: {:?})", review_id, other)),
+        };
+        if feedback.user_id != reviewer_id {
+            return Err(format!("Review {} is claimed by {}, not {}", review_id, reviewer_id, feedback.user_id));
+        }
+        if let Some(target) = &feedback.target_cognitive_state_id {
+            if *target != item.state.state_id {
+                return Err(format!("Feedback targets state {}, but review {} holds {}", target, review_id, item.state.state_id));
+            }
+        }
+        item.status = ReviewStatus::Submitted { reviewer_id, feedback };
+        Ok(())
+    }
+
+    /// Marks a submitted item as resolved and hands back its stored state and feedback.
+    pub fn take_submitted(&self, review_id: &str) -> Result<(CognitiveState, HumanFeedback), String> {
+        let mut items = self.items.lock().map_err(|_| "Review queue lock poisoned".to_string())?;
+        let item = items
+            .iter_mut()
+            .find(|item| item.review_id == review_id)
+            .ok_or_else(|| format!("Review {} not found", review_id))?;
+        match item.status.clone() {
+            ReviewStatus::Submitted { reviewer_id, feedback } => {
+                item.status = ReviewStatus::Resolved { reviewer_id };
+                Ok((item.state.clone(), feedback))
+            }
+            other => Err(format!("Review {} has no submitted feedback (status: {:?})", review_id, other)),
+        }
+    }
+
+    pub fn get(&self, review_id: &str) -> Option<ReviewItem> {
+        let items = self.items.lock().ok()?;
+        items.iter().find(|item| item.review_id == review_id).cloned()
+    }
+
+    pub fn pending_count(&self) -> usize {
+        self.items
+            .lock()
+            .map(|items| items.iter().filter(|item| item.status == ReviewStatus::Pending).count())
+            .unwrap_or(0)
+    }
+}
+
+impl Default for ReviewQueue {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use crate::polyethical_manifold::{EthicalRulebook, RulebookEthicalAssessor};
+    use crate::recursive_cognition_engine::{
+        AlignmentStatus, BasicRecursiveCognitionEngine, RecursiveCognitionEngine, Stimulus, StimulusContent,
+    };
+
+    fn create_feedback(review_state_id: &str, user_id: &str, content: &str) -> HumanFeedback {
+        HumanFeedback {
+            feedback_id: "feedback_001".to_string(),
+            target_stimulus_id: None,
+            target_cognitive_state_id: Some(review_state_id.to_string()),
+            feedback_content: content.to_string(),
+            user_id: user_id.to_string(),
+            timestamp: 1678886400,
+        }
+    }
+
+    fn create_review_engine(queue: Arc<ReviewQueue>) -> BasicRecursiveCognitionEngine {
+        BasicRecursiveCognitionEngine::new()
+            .with_ethical_assessor(RulebookEthicalAssessor::new(EthicalRulebook::builtin()))
+            .with_review_queue(queue)
+    }
+
+    fn create_review_stimulus() -> Stimulus {
+        // Triggers privacy_surveillance (0.12) only: PAS 0.88, Requires Review.
+        let mut metadata = HashMap::new();
+        metadata.insert("source".to_string(), "web_ui".to_string());
+        Stimulus {
+            id: "stimulus_review".to_string(),
+            content: StimulusContent::Text("Summarize surveillance laws".to_string()),
+            metadata,
+        }
+    }
+
+    #[test]
+    fn test_verdict_parse_round_trip() {
+        let verdicts = vec![
+            FeedbackVerdict::Approve,
+            FeedbackVerdict::Reject { reason: "biased".to_string() },
+            FeedbackVerdict::Correct { revised_hypothesis: "A better answer".to_string() },
+            FeedbackVerdict::Rating(4),
+            FeedbackVerdict::Comment("Nice work".to_string()),
+        ];
+        for verdict in verdicts {
+            assert_eq!(FeedbackVerdict::parse(&verdict.to_content()), verdict);
+        }
+        assert_eq!(FeedbackVerdict::parse("Agree"), FeedbackVerdict::Approve);
+        assert_eq!(FeedbackVerdict::parse("rating: 9"), FeedbackVerdict::Comment("rating: 9".to_string()));
+    }
+
+    #[test]
+    fn test_queue_claim_and_submit_checks_reviewer() {
+        let queue = ReviewQueue::new();
+        let state = CognitiveState { state_id: "state_q".to_string(), ..Default::default() };
+        let review_id = queue.enqueue(state, "Requires Review").unwrap();
+        assert_eq!(queue.pending_count(), 1);
+
+        let claimed = queue.fetch_pending("reviewer_a", 10).unwrap();
+        assert_eq!(claimed.len(), 1);
+        assert_eq!(queue.pending_count(), 0);
+        assert!(queue.fetch_pending("reviewer_b", 10).unwrap().is_empty());
+
+        assert!(queue.release(&review_id, "reviewer_b").is_err());
+        queue.release(&review_id, "reviewer_a").unwrap();
+        assert_eq!(queue.pending_count(), 1);
+        assert!(queue.release(&review_id, "reviewer_a").is_err()); // No longer claimed
+        assert_eq!(queue.fetch_pending("reviewer_a", 10).unwrap().len(), 1);
+
+        assert!(queue.submit_feedback(&review_id, create_feedback("state_q", "reviewer_b", "approve")).is_err());
+        assert!(queue.submit_feedback(&review_id, create_feedback("other_state", "reviewer_a", "approve")).is_err());
+        queue.submit_feedback(&review_id, create_feedback("state_q", "reviewer_a", "approve")).unwrap();
+
+        let (_, feedback) = queue.take_submitted(&review_id).unwrap();
+        assert_eq!(feedback.user_id, "reviewer_a");
+        assert_eq!(queue.get(&review_id).unwrap().status, ReviewStatus::Resolved { reviewer_id: "reviewer_a".to_string() });
+        assert!(queue.take_submitted(&review_id).is_err());
+    }
+
+    #[test]
+    fn test_engine_queues_and_resumes_after_approval() {
+        let queue = Arc::new(ReviewQueue::new());
+        let engine = create_review_engine(queue.clone());
+
+        let state = engine.execute_full_cycle(create_review_stimulus()).unwrap();
+        assert_eq!(state.ethical_assessment.as_ref().unwrap().alignment_status, AlignmentStatus::RequiresReview);
+        assert_eq!(queue.pending_count(), 1);
+
+        let item = queue.fetch_pending("reviewer_a", 1).unwrap().remove(0);
+        queue.submit_feedback(&item.review_id, create_feedback(&item.state.state_id, "reviewer_a", "approve")).unwrap();
+
+        let resumed = engine.resume_after_review(&item.review_id).unwrap();
+        assert!(resumed.confidence_level > state.confidence_level);
+        assert!(resumed.history_log.iter().any(|entry| entry.contains("from reviewer_a integrated: approved")));
+        assert!(resumed.history_log.last().unwrap().contains("resolved by reviewer_a"));
+        assert_eq!(queue.pending_count(), 0);
+    }
+
+    #[test]
+    fn test_resumed_cycle_is_finalized_like_a_fresh_one() {
+        use crate::recursive_cognition_engine::calibration::PlattCalibrator;
+        use crate::recursive_cognition_engine::working_memory::{scope_of, WorkingMemory};
+
+        let queue = Arc::new(ReviewQueue::new());
+        let memory = Arc::new(WorkingMemory::new());
+        let engine = create_review_engine(queue.clone())
+            .with_calibrator(PlattCalibrator { a: 1.0, b: 0.0 })
+            .with_working_memory(memory.clone());
+        engine.execute_full_cycle(create_review_stimulus()).unwrap();
+
+        let item = queue.fetch_pending("reviewer_a", 1).unwrap().remove(0);
+        queue.submit_feedback(&item.review_id, create_feedback(&item.state.state_id, "reviewer_a", "approve")).unwrap();
+        let resumed = engine.resume_after_review(&item.review_id).unwrap();
+
+        let calibrated = resumed.history_log.iter().filter(|entry| entry.starts_with("Confidence calibrated")).count();
+        assert_eq!(calibrated, 2); // Once in the original cycle, once when resumed
+        assert!(resumed.history_log.last().unwrap().starts_with("Confidence calibrated"));
+        let context = memory.context(scope_of(&resumed.stimulus_metadata));
+        let remembered = context.prior_states.iter().find(|prior| prior.state_id == resumed.state_id).unwrap();
+        assert_eq!(remembered.confidence_level, resumed.confidence_level);
+        assert_eq!(queue.pending_count(), 0);
+    }
+
+    #[test]
+    fn test_engine_resume_with_correction_reassesses() {
+        let queue = Arc::new(ReviewQueue::new());
+        let engine = create_review_engine(queue.clone());
+        engine.execute_full_cycle(create_review_stimulus()).unwrap();
+
+        let item = queue.fetch_pending("reviewer_a", 1).unwrap().remove(0);
+        let correction = "correct: Summary of public oversight laws for data protection";
+        queue.submit_feedback(&item.review_id, create_feedback(&item.state.state_id, "reviewer_a", correction)).unwrap();
+
+        let resumed = engine.resume_after_review(&item.review_id).unwrap();
+        assert_eq!(resumed.current_hypothesis, "Summary of public oversight laws for data protection");
+        // The stimulus text still mentions surveillance, so the corrected state is re-assessed rather than trusted.
+        let assessment = resumed.ethical_assessment.unwrap();
+        assert_eq!(assessment.alignment_status, AlignmentStatus::RequiresReview);
+        assert_eq!(queue.pending_count(), 1); // Re-queued for a second look
+    }
+}
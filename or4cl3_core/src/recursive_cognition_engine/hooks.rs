@@ -0,0 +1,434 @@
+// or4cl3_core/src/recursive_cognition_engine/hooks.rs
+
+//! Stage hooks for cross-cutting behaviour (logging, redaction, metrics, policy checks).
+//! `HookedEngine` wraps any `RecursiveCognitionEngine` and runs a chain of
+//! `StageHook`s around every stage. Hooks can observe or modify the state, or
+//! veto it, which fails the cycle.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use super::validation::ValidationIssue;
+use super::{CognitionError, CognitionStage, CognitiveState, HumanFeedback, RecursiveCognitionEngine, Stimulus};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum HookDecision {
+    Continue,
+    Veto(String), // Reason; the stage fails with it
+}
+
+/// Runs before and after each stage. `before_stage` sees the stage's input and is not
+/// called for Initialization, which has no input state; `after_stage` sees its output.
+pub trait StageHook {
+    fn name(&self) -> &str;
+
+    fn before_stage(&self, _stage: CognitionStage, _state: &mut CognitiveState) -> HookDecision {
+        HookDecision::Continue
+    }
+
+    fn after_stage(&self, _stage: CognitionStage, _state: &mut CognitiveState) -> HookDecision {
+        HookDecision::Continue
+    }
+}
+
+/// Lets a hook be registered while the caller keeps a handle, e.g. to read `StageMetricsHook` metrics.
+impl<H: StageHook + ?Sized> StageHook for Arc<H> {
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+
+    fn before_stage(&self, stage: CognitionStage, state: &mut CognitiveState) -> HookDecision {
+        (**self).before_stage(stage, state)
+    }
+
+    fn after_stage(&self, stage: CognitionStage, state: &mut CognitiveState) -> HookDecision {
+        (**self).after_stage(stage, state)
+    }
+}
+
+/// Wraps an engine with a hook chain. `before_stage` hooks run in registration order and
+/// `after_stage` hooks in reverse, so the first hook registered is the outermost.
+/// A veto fails the stage with a `ValidationFailure`.
+pub struct HookedEngine<E: RecursiveCognitionEngine> {
+    engine: E,
+    hooks: Vec<Box<dyn StageHook + Send + Sync>>,
+}
+
+impl<E: RecursiveCognitionEngine> HookedEngine<E> {
+    pub fn new(engine: E) -> Self {
+        Self { engine, hooks: Vec::new() }
+    }
+
+    pub fn with_hook(mut self, hook: impl StageHook + Send + Sync + 'static) -> Self {
+        self.hooks.push(Box::new(hook));
+        self
+    }
+
+    pub fn inner(&self) -> &E {
+        &self.engine
+    }
+
+    fn veto_error(stage: CognitionStage, state: &CognitiveState, hook: &dyn StageHook, reason: &str) -> CognitionError {
+        CognitionError::validation(Some(stage), format!("vetoed by hook '{}': {}", hook.name(), reason)).for_state(&state.state_id)
+    }
+
+    fn run_before(&self, stage: CognitionStage, state: &mut CognitiveState) -> Result<(), CognitionError> {
+        for hook in &self.hooks {
+            if let HookDecision::Veto(reason) = hook.before_stage(stage, state) {
+                return Err(Self::veto_error(stage, state, hook.as_ref(), &reason));
+            }
+        }
+        Ok(())
+    }
+
+    fn run_after(&self, stage: CognitionStage, state: &mut CognitiveState) -> Result<(), CognitionError> {
+        for hook in self.hooks.iter().rev() {
+            if let HookDecision::Veto(reason) = hook.after_stage(stage, state) {
+                return Err(Self::veto_error(stage, state, hook.as_ref(), &reason));
+            }
+        }
+        Ok(())
+    }
+
+    fn hooked(
+        &self,
+        stage: CognitionStage,
+        state: &CognitiveState,
+        run: impl FnOnce(&CognitiveState) -> Result<CognitiveState, CognitionError>,
+    ) -> Result<CognitiveState, CognitionError> {
+        let mut input = state.clone();
+        self.run_before(stage, &mut input)?;
+        let mut output = run(&input)?;
+        self.run_after(stage, &mut output)?;
+        Ok(output)
+    }
+}
+
+impl<E: RecursiveCognitionEngine> RecursiveCognitionEngine for HookedEngine<E> {
+    fn initialize_state_from_stimulus(&self, stimulus: Stimulus) -> Result<CognitiveState, CognitionError> {
+        let mut state = self.engine.initialize_state_from_stimulus(stimulus)?;
+        self.run_after(CognitionStage::Initialization, &mut state)?;
+        Ok(state)
+    }
+
+    fn assess_ethics(&self, state: &CognitiveState) -> Result<CognitiveState, CognitionError> {
+        self.hooked(CognitionStage::EthicalAssessment, state, |input| self.engine.assess_ethics(input))
+    }
+
+    fn refine_cognition(&self, state: &CognitiveState) -> Result<CognitiveState, CognitionError> {
+        self.hooked(CognitionStage::Refinement, state, |input| self.engine.refine_cognition(input))
+    }
+
+    fn validate_self(&self, state: &CognitiveState) -> Result<CognitiveState, CognitionError> {
+        self.hooked(CognitionStage::SelfValidation, state, |input| self.engine.validate_self(input))
+    }
+
+    fn incorporate_feedback(&self, state: &CognitiveState, feedback: HumanFeedback) -> Result<CognitiveState, CognitionError> {
+        self.engine.incorporate_feedback(state, feedback) // Not a cycle stage, so no hooks
+    }
+
+    fn finalize_cycle(&self, state: &CognitiveState) -> Result<CognitiveState, CognitionError> {
+        self.hooked(CognitionStage::Finalization, state, |input| self.engine.finalize_cycle(input))
+    }
+
+    fn configuration_versions(&self) -> BTreeMap<String, String> {
+        let mut versions = self.engine.configuration_versions();
+        let names: Vec<&str> = self.hooks.iter().map(|hook| hook.name()).collect();
+        versions.insert("hooks".to_string(), names.join(","));
+        versions
+    }
+
+    fn execute_full_cycle(&self, stimulus: Stimulus) -> Result<CognitiveState, CognitionError> {
+        let mut state = self.initialize_state_from_stimulus(stimulus)?;
+        let mut stage = CognitionStage::Initialization;
+        while let Some(next) = stage.next() {
+            state = self.run_stage(next, &state)?;
+            stage = next;
+        }
+        Ok(state)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct StageMetrics {
+    pub runs: u64, // Completed runs; failed or vetoed runs are not counted
+    pub total_duration: Duration,
+}
+
+/// Records how often each stage completes and how long it takes.
+/// Register it first so its timing includes the other hooks.
+pub struct StageMetricsHook {
+    started: Mutex<HashMap<(String, CognitionStage), Instant>>, // (state_id, stage) -> start time
+    metrics: Mutex<BTreeMap<CognitionStage, StageMetrics>>,
+}
+
+impl StageMetricsHook {
+    pub fn new() -> Self {
+        Self { started: Mutex::new(HashMap::new()), metrics: Mutex::new(BTreeMap::new()) }
+    }
+
+    pub fn snapshot(&self) -> BTreeMap<CognitionStage, StageMetrics> {
+        self.metrics.lock().map(|metrics| metrics.clone()).unwrap_or_default()
+    }
+}
+
+impl Default for StageMetricsHook {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StageHook for StageMetricsHook {
+    fn name(&self) -> &str {
+        "stage_metrics"
+    }
+
+    fn before_stage(&self, stage: CognitionStage, state: &mut CognitiveState) -> HookDecision {
+        if let Ok(mut started) = self.started.lock() {
+            started.insert((state.state_id.clone(), stage), Instant::now());
+        }
+        HookDecision::Continue
+    }
+
+    fn after_stage(&self, stage: CognitionStage, state: &mut CognitiveState) -> HookDecision {
+        let started = self.started.lock().ok().and_then(|mut started| started.remove(&(state.state_id.clone(), stage)));
+        if let Ok(mut metrics) = self.metrics.lock() {
+            let entry = metrics.entry(stage).or_default();
+            entry.runs += 1;
+            entry.total_duration += started.map(|start| start.elapsed()).unwrap_or_default();
+        }
+        HookDecision::Continue
+    }
+}
+
+/// Replaces sensitive terms (ASCII case-insensitive) after every stage, so they never
+/// reach later stages or the emitted state. Covers the state and stimulus IDs, the stimulus
+/// text and metadata values, the hypothesis, the assessment's hypothesis, concerns and
+/// mitigations, the working context, validation issue details and the history log.
+pub struct RedactionHook {
+    terms: Vec<String>,
+}
+
+impl RedactionHook {
+    pub const PLACEHOLDER: &'static str = "[REDACTED]";
+
+    pub fn new(terms: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        let terms = terms.into_iter().map(Into::into).filter(|term: &String| !term.is_empty()).collect();
+        Self { terms }
+    }
+
+    fn redact(&self, text: &str) -> String {
+        let mut redacted = text.to_string();
+        for term in &self.terms {
+            let needle = term.to_ascii_lowercase();
+            let mut search_from = 0;
+            while let Some(offset) = redacted[search_from..].to_ascii_lowercase().find(&needle) {
+                let start = search_from + offset;
+                redacted.replace_range(start..start + needle.len(), Self::PLACEHOLDER);
+                search_from = start + Self::PLACEHOLDER.len();
+            }
+        }
+        redacted
+    }
+}
+
+impl StageHook for RedactionHook {
+    fn name(&self) -> &str {
+        "redaction"
+    }
+
+    fn after_stage(&self, _stage: CognitionStage, state: &mut CognitiveState) -> HookDecision {
+        state.state_id = self.redact(&state.state_id);
+        state.stimulus_id = self.redact(&state.stimulus_id);
+        state.stimulus_text = self.redact(&state.stimulus_text);
+        for value in state.stimulus_metadata.values_mut() {
+            *value = self.redact(value);
+        }
+        state.current_hypothesis = self.redact(&state.current_hypothesis);
+        if let Some(assessment) = state.ethical_assessment.as_mut() {
+            assessment.assessed_hypothesis = self.redact(&assessment.assessed_hypothesis);
+            for text in assessment.ethical_concerns.iter_mut().chain(assessment.suggested_mitigations.iter_mut()) {
+                *text = self.redact(text);
+            }
+        }
+        if let Some(context) = state.working_context.as_mut() {
+            for prior in context.prior_states.iter_mut() {
+                prior.state_id = self.redact(&prior.state_id);
+                prior.stimulus_id = self.redact(&prior.stimulus_id);
+                prior.hypothesis = self.redact(&prior.hypothesis);
+            }
+            for text in context.entities.iter_mut().chain(context.unresolved_concerns.iter_mut()).chain(context.summary.iter_mut()) {
+                *text = self.redact(text);
+            }
+        }
+        for issue in state.validation_issues.iter_mut() {
+            match issue {
+                ValidationIssue::ContradictedByEvidence { claim_id } | ValidationIssue::UnresolvedEvidence { claim_id } => {
+                    *claim_id = self.redact(claim_id);
+                }
+                ValidationIssue::HistoryGap { missing_marker } => *missing_marker = self.redact(missing_marker),
+                _ => {}
+            }
+        }
+        for entry in state.history_log.iter_mut() {
+            *entry = self.redact(entry);
+        }
+        HookDecision::Continue
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::recursive_cognition_engine::working_memory::{PriorState, WorkingContext};
+    use crate::recursive_cognition_engine::{BasicRecursiveCognitionEngine, StimulusContent};
+
+    fn create_test_stimulus() -> Stimulus {
+        Stimulus {
+            id: "hooked_stimulus".to_string(),
+            content: StimulusContent::Text("Hook me.".to_string()),
+            metadata: HashMap::new(),
+        }
+    }
+
+    /// Logs every hook call into a shared trace.
+    struct TraceHook {
+        name: String,
+        trace: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl StageHook for TraceHook {
+        fn name(&self) -> &str {
+            &self.name
+        }
+        fn before_stage(&self, stage: CognitionStage, _state: &mut CognitiveState) -> HookDecision {
+            self.trace.lock().unwrap().push(format!("{} before {}", self.name, stage));
+            HookDecision::Continue
+        }
+        fn after_stage(&self, stage: CognitionStage, _state: &mut CognitiveState) -> HookDecision {
+            self.trace.lock().unwrap().push(format!("{} after {}", self.name, stage));
+            HookDecision::Continue
+        }
+    }
+
+    /// Policy check that vetoes refined states below a confidence floor.
+    struct ConfidenceFloorHook(f64);
+
+    impl StageHook for ConfidenceFloorHook {
+        fn name(&self) -> &str {
+            "confidence_floor"
+        }
+        fn after_stage(&self, stage: CognitionStage, state: &mut CognitiveState) -> HookDecision {
+            if stage == CognitionStage::Refinement && state.confidence_level < self.0 {
+                return HookDecision::Veto(format!("confidence {:.2} below {:.2}", state.confidence_level, self.0));
+            }
+            HookDecision::Continue
+        }
+    }
+
+    #[test]
+    fn test_hooks_run_around_every_stage_in_onion_order() {
+        let trace = Arc::new(Mutex::new(Vec::new()));
+        let engine = HookedEngine::new(BasicRecursiveCognitionEngine::new())
+            .with_hook(TraceHook { name: "outer".to_string(), trace: trace.clone() })
+            .with_hook(TraceHook { name: "inner".to_string(), trace: trace.clone() });
+        engine.execute_full_cycle(create_test_stimulus()).unwrap();
+
+        let trace = trace.lock().unwrap();
+        assert_eq!(&trace[..2], ["inner after initialization", "outer after initialization"]);
+        assert_eq!(
+            &trace[2..6],
+            ["outer before ethical assessment", "inner before ethical assessment", "inner after ethical assessment", "outer after ethical assessment"]
+        );
+        assert_eq!(trace.len(), 2 + 4 * 4);
+        assert_eq!(engine.configuration_versions().get("hooks").map(String::as_str), Some("outer,inner"));
+    }
+
+    #[test]
+    fn test_veto_fails_the_cycle() {
+        let engine = HookedEngine::new(BasicRecursiveCognitionEngine::new()).with_hook(ConfidenceFloorHook(0.9));
+        let error = engine.execute_full_cycle(create_test_stimulus()).unwrap_err();
+        assert_eq!(error.stage, Some(CognitionStage::Refinement));
+        assert_eq!(error.state_id.as_deref(), Some("state_for_stimulus_hooked_stimulus"));
+        assert!(error.to_string().starts_with("refinement failed for state state_for_stimulus_hooked_stimulus: validation failed: vetoed by hook 'confidence_floor'"));
+        assert!(!error.is_retryable());
+
+        let permissive = HookedEngine::new(BasicRecursiveCognitionEngine::new()).with_hook(ConfidenceFloorHook(0.1));
+        assert!(permissive.execute_full_cycle(create_test_stimulus()).is_ok());
+    }
+
+    /// Copies a sensitive term into every derived field the redaction hook must cover.
+    struct LeakyHook(&'static str);
+
+    impl StageHook for LeakyHook {
+        fn name(&self) -> &str {
+            "leaky"
+        }
+        fn after_stage(&self, stage: CognitionStage, state: &mut CognitiveState) -> HookDecision {
+            if stage != CognitionStage::SelfValidation {
+                return HookDecision::Continue;
+            }
+            let leak = self.0.to_string();
+            if let Some(assessment) = state.ethical_assessment.as_mut() {
+                assessment.ethical_concerns.push(format!("Mentions {}", leak));
+                assessment.suggested_mitigations.push(format!("Drop {}", leak));
+            }
+            state.working_context = Some(WorkingContext {
+                prior_states: vec![PriorState {
+                    state_id: leak.clone(),
+                    stimulus_id: leak.clone(),
+                    hypothesis: leak.clone(),
+                    confidence_level: 0.5,
+                }],
+                entities: vec![leak.clone()],
+                unresolved_concerns: vec![leak.clone()],
+                summary: vec![leak.clone()],
+            });
+            state.validation_issues = vec![
+                ValidationIssue::ContradictedByEvidence { claim_id: leak.clone() },
+                ValidationIssue::UnresolvedEvidence { claim_id: leak.clone() },
+                ValidationIssue::HistoryGap { missing_marker: leak },
+            ];
+            HookDecision::Continue
+        }
+    }
+
+    #[test]
+    fn test_redaction_covers_derived_fields() {
+        // Registered after the redaction hook, so its after_stage runs first.
+        let engine = HookedEngine::new(BasicRecursiveCognitionEngine::new())
+            .with_hook(RedactionHook::new(["555-0100"]))
+            .with_hook(LeakyHook("555-0100"));
+        let state = engine.execute_full_cycle(create_test_stimulus()).unwrap();
+
+        let assessment = state.ethical_assessment.as_ref().unwrap();
+        assert!(assessment.ethical_concerns.contains(&"Mentions [REDACTED]".to_string()));
+        assert!(assessment.suggested_mitigations.contains(&"Drop [REDACTED]".to_string()));
+        assert_eq!(state.working_context.as_ref().unwrap().entities, vec!["[REDACTED]".to_string()]);
+        assert_eq!(state.validation_issues.len(), 3);
+        assert!(!serde_json::to_string(&state).unwrap().contains("555-0100"));
+    }
+
+    #[test]
+    fn test_redaction_and_metrics_hooks() {
+        let metrics = Arc::new(StageMetricsHook::new());
+        let engine = HookedEngine::new(BasicRecursiveCognitionEngine::new())
+            .with_hook(metrics.clone())
+            .with_hook(RedactionHook::new(["HOOKED_STIMULUS", "hook me", "555-0100"]));
+        let mut stimulus = create_test_stimulus();
+        stimulus.metadata.insert("user_phone".to_string(), "555-0100".to_string());
+        let state = engine.execute_full_cycle(stimulus).unwrap();
+        assert_eq!(state.current_hypothesis, "Initial hypothesis for stimulus: [REDACTED] (refined)");
+        assert!(state.history_log.iter().all(|entry| !entry.contains("hooked_stimulus")));
+        assert_eq!(state.stimulus_text, "[REDACTED].");
+        assert_eq!(state.stimulus_id, "[REDACTED]");
+        assert_eq!(state.state_id, "state_for_stimulus_[REDACTED]");
+        assert_eq!(state.stimulus_metadata.get("user_phone").map(String::as_str), Some("[REDACTED]"));
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.len(), CognitionStage::ALL.len());
+        assert!(snapshot.values().all(|stage_metrics| stage_metrics.runs == 1));
+    }
+}
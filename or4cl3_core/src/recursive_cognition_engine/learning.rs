@@ -0,0 +1,371 @@
+// or4cl3_core/src/recursive_cognition_engine/learning.rs
+
+//! Learning from accumulated human feedback.
+//! The `FeedbackStore` aggregates feedback over time and nudges shared engine
+//! parameters (ethical rule weights, confidence bias, evidence weighting).
+//! Every adjustment is recorded against the feedback that caused it, so it can
+//! be inspected and reverted.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+
+use super::feedback::FeedbackVerdict;
+use super::{AlignmentStatus, CognitiveState, HumanFeedback};
+
+/// Additive offset applied to final cycle confidence (default 0.0).
+pub const CONFIDENCE_BIAS: &str = "calibration.confidence_bias";
+/// Additive offset applied to the evidence refiner's evidence weight (default 0.0).
+pub const EVIDENCE_WEIGHT_OFFSET: &str = "refinement.evidence_weight_offset";
+
+/// Key of the multiplier applied to a rule's rulebook weight (default 1.0).
+pub fn rule_weight_key(rule_id: &str) -> String {
+    format!("rule_weight.{}", rule_id)
+}
+
+/// Tunable engine parameters, keyed by name. Unset parameters take their default.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EngineParameters {
+    values: HashMap<String, f64>,
+}
+
+impl EngineParameters {
+    pub fn default_for(key: &str) -> f64 {
+        if key.starts_with("rule_weight.") { 1.0 } else { 0.0 }
+    }
+
+    pub fn get(&self, key: &str) -> f64 {
+        self.values.get(key).copied().unwrap_or_else(|| Self::default_for(key))
+    }
+
+    pub fn set(&mut self, key: &str, value: f64) {
+        self.values.insert(key.to_string(), value);
+    }
+
+    /// Explicitly set parameters only; defaults are omitted.
+    pub fn snapshot(&self) -> HashMap<String, f64> {
+        self.values.clone()
+    }
+}
+
+/// Parameters shared between the feedback store and the components that read them.
+pub type SharedParameters = Arc<RwLock<EngineParameters>>;
+
+/// Reads a parameter, falling back to its default if the lock is poisoned.
+pub fn read_parameter(parameters: &SharedParameters, key: &str) -> f64 {
+    parameters
+        .read()
+        .map(|parameters| parameters.get(key))
+        .unwrap_or_else(|_| EngineParameters::default_for(key))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParameterChange {
+    pub change_id: u64,
+    pub feedback_id: String,
+    pub parameter: String,
+    pub old_value: f64,
+    pub new_value: f64,
+    pub min: f64, // Bounds the parameter was clamped to; reverting stays within them
+    pub max: f64,
+    pub reverted: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct LearningConfig {
+    pub learning_rate: f64,    // Step size for every adjustment
+    pub max_rule_weight: f64,  // Rule weight multipliers stay within 0.0..=max_rule_weight
+    pub max_confidence_bias: f64, // Confidence bias stays within -max..=max
+}
+
+impl Default for LearningConfig {
+    fn default() -> Self {
+        Self { learning_rate: 0.05, max_rule_weight: 3.0, max_confidence_bias: 0.25 }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FeedbackSummary {
+    pub total: usize,
+    pub approvals: usize,
+    pub rejections: usize,
+    pub corrections: usize,
+    pub comments: usize,
+    pub mean_rating: Option<f64>,
+}
+
+#[derive(Debug, Default)]
+struct FeedbackLedger {
+    verdicts: Vec<(String, FeedbackVerdict)>, // (feedback_id, verdict)
+    changes: Vec<ParameterChange>,
+}
+
+pub struct FeedbackStore {
+    parameters: SharedParameters,
+    ledger: Mutex<FeedbackLedger>,
+    config: LearningConfig,
+}
+
+impl FeedbackStore {
+    pub fn new(parameters: SharedParameters) -> Self {
+        Self { parameters, ledger: Mutex::new(FeedbackLedger::default()), config: LearningConfig::default() }
+    }
+
+    pub fn with_config(mut self, config: LearningConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    pub fn parameters(&self) -> SharedParameters {
+        self.parameters.clone()
+    }
+
+    /// Records feedback on a state and applies the resulting parameter adjustments:
+    /// - confidence bias moves towards the observed outcome (approve = 1, reject/correct = 0, ratings in between);
+    /// - rules that flagged a state are weakened when a reviewer approves it anyway and strengthened on rejection;
+    /// - evidence weighting grows when an evidence-backed state is approved and shrinks when it is corrected.
+    ///
+    /// Fails without changing anything if feedback with the same `feedback_id` was already recorded.
+    pub fn record_feedback(&self, state: &CognitiveState, feedback: &HumanFeedback) -> Result<Vec<ParameterChange>, String> {
+        let verdict = FeedbackVerdict::parse(&feedback.feedback_content);
+        let rate = self.config.learning_rate;
+        let mut adjustments: Vec<(String, f64, f64, f64)> = Vec::new(); // (key, delta, min, max)
+
+        let outcome = match &verdict {
+            FeedbackVerdict::Approve => Some(1.0),
+            FeedbackVerdict::Reject { .. } | FeedbackVerdict::Correct { .. } => Some(0.0),
+            FeedbackVerdict::Rating(rating) => Some((*rating as f64 - 1.0) / 4.0),
+            FeedbackVerdict::Comment(_) => None,
+        };
+        if let Some(outcome) = outcome {
+            let bias = self.config.max_confidence_bias;
+            adjustments.push((CONFIDENCE_BIAS.to_string(), rate * (outcome - state.confidence_level), -bias, bias));
+        }
+
+        if let Some(assessment) = &state.ethical_assessment {
+            let flagged = assessment.alignment_status != AlignmentStatus::Aligned;
+            let direction = match &verdict {
+                FeedbackVerdict::Approve if flagged => Some(-1.0),
+                FeedbackVerdict::Reject { .. } => Some(1.0),
+                _ => None,
+            };
+            if let Some(direction) = direction {
+                let mut rule_ids: Vec<&String> = assessment.pas_breakdown.keys().collect();
+                rule_ids.sort();
+                for rule_id in rule_ids {
+                    adjustments.push((rule_weight_key(rule_id), direction * rate, 0.0, self.config.max_rule_weight));
+                }
+            }
+        }
+
+        if !state.supporting_evidence_ids.is_empty() {
+            let direction = match &verdict {
+                FeedbackVerdict::Approve => Some(1.0),
+                FeedbackVerdict::Correct { .. } => Some(-1.0),
+                _ => None,
+            };
+            if let Some(direction) = direction {
+                adjustments.push((EVIDENCE_WEIGHT_OFFSET.to_string(), direction * rate, -1.0, 1.0));
+            }
+        }
+
+        let mut ledger = self.ledger.lock().map_err(|_| "Feedback store lock poisoned".to_string())?;
+        if ledger.verdicts.iter().any(|(feedback_id, _)| *feedback_id == feedback.feedback_id) {
+            return Err(format!("Feedback {} was already recorded", feedback.feedback_id));
+        }
+        let mut parameters = self.parameters.write().map_err(|_| "Engine parameters lock poisoned".to_string())?;
+        let mut applied = Vec::new();
+        for (key, delta, min, max) in adjustments {
+            let old_value = parameters.get(&key);
+            let new_value = (old_value + delta).clamp(min, max);
+            if new_value == old_value {
+                continue;
+            }
+            parameters.set(&key, new_value);
+            let change = ParameterChange {
+                change_id: ledger.changes.len() as u64 + 1,
+                feedback_id: feedback.feedback_id.clone(),
+                parameter: key,
+                old_value,
+                new_value,
+                min,
+                max,
+                reverted: false,
+            };
+            ledger.changes.push(change.clone());
+            applied.push(change);
+        }
+        ledger.verdicts.push((feedback.feedback_id.clone(), verdict));
+        Ok(applied)
+    }
+
+    pub fn summary(&self) -> FeedbackSummary {
+        let ledger = match self.ledger.lock() {
+            Ok(ledger) => ledger,
+            Err(_) => return FeedbackSummary::default(),
+        };
+        let mut summary = FeedbackSummary { total: ledger.verdicts.len(), ..Default::default() };
+        let mut ratings = Vec::new();
+        for (_, verdict) in &ledger.verdicts {
+            match verdict {
+                FeedbackVerdict::Approve => summary.approvals += 1,
+                FeedbackVerdict::Reject { .. } => summary.rejections += 1,
+                FeedbackVerdict::Correct { .. } => summary.corrections += 1,
+                FeedbackVerdict::Rating(rating) => ratings.push(*rating as f64),
+                FeedbackVerdict::Comment(_) => summary.comments += 1,
+            }
+        }
+        if !ratings.is_empty() {
+            summary.mean_rating = Some(ratings.iter().sum::<f64>() / ratings.len() as f64);
+        }
+        summary
+    }
+
+    /// All parameter changes, in the order they were applied.
+    pub fn changes(&self) -> Vec<ParameterChange> {
+        self.ledger.lock().map(|ledger| ledger.changes.clone()).unwrap_or_default()
+    }
+
+    pub fn changes_for_feedback(&self, feedback_id: &str) -> Vec<ParameterChange> {
+        self.changes().into_iter().filter(|change| change.feedback_id == feedback_id).collect()
+    }
+
+    /// Undoes a single change. Later changes to the same parameter are kept:
+    /// only this change's delta is subtracted from the current value, and the
+    /// result is clamped to the bounds the change was made within.
+    pub fn revert_change(&self, change_id: u64) -> Result<(), String> {
+        let mut ledger = self.ledger.lock().map_err(|_| "Feedback store lock poisoned".to_string())?;
+        let change = ledger
+            .changes
+            .iter_mut()
+            .find(|change| change.change_id == change_id)
+            .ok_or_else(|| format!("Parameter change {} not found", change_id))?;
+        if change.reverted {
+            return Err(format!("Parameter change {} is already reverted", change_id));
+        }
+        let mut parameters = self.parameters.write().map_err(|_| "Engine parameters lock poisoned".to_string())?;
+        let current = parameters.get(&change.parameter);
+        let reverted = (current - (change.new_value - change.old_value)).clamp(change.min, change.max);
+        parameters.set(&change.parameter, reverted);
+        change.reverted = true;
+        Ok(())
+    }
+
+    /// Undoes every change caused by the given feedback. Returns how many changes were reverted.
+    pub fn revert_feedback(&self, feedback_id: &str) -> Result<usize, String> {
+        let change_ids: Vec<u64> = self
+            .changes_for_feedback(feedback_id)
+            .into_iter()
+            .filter(|change| !change.reverted)
+            .map(|change| change.change_id)
+            .collect();
+        for change_id in &change_ids {
+            self.revert_change(*change_id)?;
+        }
+        Ok(change_ids.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::recursive_cognition_engine::EthicalAssessmentReport;
+
+    fn create_feedback(feedback_id: &str, content: &str) -> HumanFeedback {
+        HumanFeedback {
+            feedback_id: feedback_id.to_string(),
+            target_stimulus_id: None,
+            target_cognitive_state_id: Some("state_learning".to_string()),
+            feedback_content: content.to_string(),
+            user_id: "reviewer_a".to_string(),
+            timestamp: 1678886400,
+        }
+    }
+
+    fn create_flagged_state() -> CognitiveState {
+        CognitiveState {
+            state_id: "state_learning".to_string(),
+            confidence_level: 0.6,
+            supporting_evidence_ids: vec!["claim_a".to_string()],
+            ethical_assessment: Some(EthicalAssessmentReport {
+                pas_score: 0.88,
+                alignment_status: AlignmentStatus::RequiresReview,
+                pas_breakdown: HashMap::from([("privacy_surveillance".to_string(), 0.12)]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn create_store() -> FeedbackStore {
+        FeedbackStore::new(Arc::new(RwLock::new(EngineParameters::default())))
+    }
+
+    #[test]
+    fn test_approval_of_flagged_state_adjusts_parameters() {
+        let store = create_store();
+        let changes = store.record_feedback(&create_flagged_state(), &create_feedback("fb_1", "approve")).unwrap();
+        assert_eq!(changes.len(), 3);
+
+        let parameters = store.parameters();
+        assert!((read_parameter(&parameters, CONFIDENCE_BIAS) - 0.02).abs() < 1e-9); // 0.05 * (1.0 - 0.6)
+        assert!((read_parameter(&parameters, &rule_weight_key("privacy_surveillance")) - 0.95).abs() < 1e-9);
+        assert!((read_parameter(&parameters, EVIDENCE_WEIGHT_OFFSET) - 0.05).abs() < 1e-9);
+        assert!(changes.iter().all(|change| change.feedback_id == "fb_1"));
+
+        // Resubmitting the same feedback changes nothing
+        let snapshot = parameters.read().unwrap().snapshot();
+        let err = store.record_feedback(&create_flagged_state(), &create_feedback("fb_1", "approve")).unwrap_err();
+        assert!(err.contains("fb_1 was already recorded"));
+        assert_eq!(parameters.read().unwrap().snapshot(), snapshot);
+        assert_eq!(store.changes().len(), 3);
+        assert_eq!(store.summary().total, 1);
+    }
+
+    #[test]
+    fn test_summary_aggregates_ratings_and_corrections() {
+        let store = create_store();
+        let state = create_flagged_state();
+        store.record_feedback(&state, &create_feedback("fb_1", "rating: 5")).unwrap();
+        store.record_feedback(&state, &create_feedback("fb_2", "rating: 2")).unwrap();
+        store.record_feedback(&state, &create_feedback("fb_3", "correct: Better")).unwrap();
+        store.record_feedback(&state, &create_feedback("fb_4", "Interesting")).unwrap();
+
+        let summary = store.summary();
+        assert_eq!(summary.total, 4);
+        assert_eq!(summary.corrections, 1);
+        assert_eq!(summary.comments, 1);
+        assert_eq!(summary.mean_rating, Some(3.5));
+        assert!(store.changes_for_feedback("fb_4").is_empty()); // Comments carry no learning signal
+    }
+
+    #[test]
+    fn test_revert_feedback_restores_only_its_changes() {
+        let store = create_store();
+        let state = create_flagged_state();
+        store.record_feedback(&state, &create_feedback("fb_1", "reject: unsafe")).unwrap();
+        store.record_feedback(&state, &create_feedback("fb_2", "reject: still unsafe")).unwrap();
+        let key = rule_weight_key("privacy_surveillance");
+        assert!((read_parameter(&store.parameters(), &key) - 1.1).abs() < 1e-9);
+
+        assert_eq!(store.revert_feedback("fb_1").unwrap(), 2);
+        assert!((read_parameter(&store.parameters(), &key) - 1.05).abs() < 1e-9);
+        assert!(store.changes_for_feedback("fb_1").iter().all(|change| change.reverted));
+        assert_eq!(store.revert_feedback("fb_1").unwrap(), 0);
+        assert!(store.revert_change(1).is_err());
+    }
+
+    #[test]
+    fn test_revert_after_clamped_feedback_stays_within_bounds() {
+        let config = LearningConfig { max_rule_weight: 1.0, ..Default::default() };
+        let store = create_store().with_config(config);
+        let state = create_flagged_state();
+        let key = rule_weight_key("privacy_surveillance");
+        store.record_feedback(&state, &create_feedback("fb_1", "approve")).unwrap(); // 1.0 -> 0.95
+        store.record_feedback(&state, &create_feedback("fb_2", "reject: unsafe")).unwrap(); // 0.95 -> 1.0
+        store.record_feedback(&state, &create_feedback("fb_3", "reject: unsafe")).unwrap(); // Clamped at 1.0
+        assert!(store.changes_for_feedback("fb_3").iter().all(|change| change.parameter != key));
+
+        store.revert_feedback("fb_1").unwrap();
+        assert_eq!(read_parameter(&store.parameters(), &key), 1.0); // Not 1.05
+    }
+}
@@ -1,10 +1,33 @@
 // or4cl3_core/src/recursive_cognition_engine/mod.rs
-use std::collections::HashMap;
-// Assuming serde_json for structured data in StimulusContent, if not, it can be removed.
-// Add `serde::{Serialize, Deserialize}` if these structs need to be (de)serialized.
-// For now, let's keep it minimal and add serde later if explicitly needed by a step.
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
 
-#[derive(Debug, Clone)]
+use serde::{Deserialize, Serialize};
+
+pub mod async_engine;
+pub mod calibration;
+pub mod checkpoint;
+pub mod envelope;
+pub mod evidence;
+pub mod feedback;
+pub mod hooks;
+pub mod learning;
+pub mod replay;
+pub mod retry;
+#[cfg(test)]
+pub(crate) mod test_engine;
+pub mod validation;
+pub mod working_memory;
+
+use calibration::{clamp_confidence, ConfidenceCalibrator};
+use envelope::StimulusEnvelope;
+use feedback::{FeedbackVerdict, ReviewQueue, VerdictFeedbackIntegrator};
+use learning::{read_parameter, FeedbackStore, CONFIDENCE_BIAS};
+use working_memory::{WorkingContext, WorkingMemory};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum StimulusContent {
     Text(String),
     Image(Vec<u8>), // Placeholder for image data
@@ -12,34 +35,110 @@ pub enum StimulusContent {
                             // Consider using serde_json::Value if parsing is needed within this struct
 }
 
-#[derive(Debug, Clone)]
+impl StimulusContent {
+    /// Returns the textual form of the content, if it has one.
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            StimulusContent::Text(text) | StimulusContent::StructuredData(text) => Some(text),
+            StimulusContent::Image(_) => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Stimulus {
     pub id: String,
     pub content: StimulusContent,
-    pub metadata: HashMap<String, String>, // e.g., source, timestamp, user_id
+    pub metadata: HashMap<String, String>, // Well-known keys (source, timestamp, user_id, ...) are defined in `envelope`
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum AlignmentStatus {
+    Aligned,
+    #[default]
+    RequiresReview, // Unassessed reports fail closed
+    Misaligned,
+}
+
+impl fmt::Display for AlignmentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AlignmentStatus::Aligned => write!(f, "Aligned"),
+            AlignmentStatus::RequiresReview => write!(f, "Requires Review"),
+            AlignmentStatus::Misaligned => write!(f, "Misaligned"),
+        }
+    }
+}
+
+/// PAS score boundaries between alignment statuses.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PasThresholds {
+    pub aligned: f64,         // PAS at or above this is Aligned
+    pub requires_review: f64, // PAS at or above this (but below `aligned`) Requires Review
+}
+
+impl PasThresholds {
+    pub fn new(aligned: f64, requires_review: f64) -> Result<Self, String> {
+        if !(0.0..=1.0).contains(&aligned) || !(0.0..=1.0).contains(&requires_review) {
+            return Err("PAS thresholds must be within 0.0..=1.0".to_string());
+        }
+        if requires_review > aligned {
+            return Err(format!(
+                "Review threshold {} cannot exceed aligned threshold {}",
+                requires_review, aligned
+            ));
+        }
+        Ok(Self { aligned, requires_review })
+    }
+
+    pub fn classify(&self, pas_score: f64) -> AlignmentStatus {
+        if pas_score >= self.aligned {
+            AlignmentStatus::Aligned
+        } else if pas_score >= self.requires_review {
+            AlignmentStatus::RequiresReview
+        } else {
+            AlignmentStatus::Misaligned
+        }
+    }
+}
+
+impl Default for PasThresholds {
+    fn default() -> Self {
+        Self { aligned: 0.91, requires_review: 0.75 }
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct EthicalAssessmentReport {
-    pub pas_score: f64, // Phase-Autonomous Sovereignty score (e.g., >= 0.91)
+    pub pas_score: f64, // Phase-Autonomous Sovereignty score, classified by PasThresholds
     pub ethical_concerns: Vec<String>,
     pub suggested_mitigations: Vec<String>,
-    pub alignment_status: String, // e.g., "Aligned", "Requires Review", "Misaligned"
+    pub alignment_status: AlignmentStatus,
+    pub pas_breakdown: HashMap<String, f64>, // PAS penalty contributed by each triggered rule
+    pub assessed_hypothesis: String, // Hypothesis the assessment was made for; stale once it changes
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CognitiveState {
     pub state_id: String,
     pub stimulus_id: String, // ID of the stimulus that initiated or is related to this state
+    pub stimulus_text: String, // Textual content of the stimulus, empty for non-text content
+    pub stimulus_metadata: HashMap<String, String>, // Carried over so later stages can use it
     pub current_hypothesis: String, // Could be a textual summary or a structured representation
     pub confidence_level: f64,
     pub supporting_evidence_ids: Vec<String>, // Links to Mythos Memory Core claims or other data
     pub ethical_assessment: Option<EthicalAssessmentReport>,
     pub history_log: Vec<String>, // Log of processing steps taken to reach this state
                                   // May include versioning or branching info for recursive thoughts
+    #[serde(default)]
+    pub working_context: Option<WorkingContext>, // Prior cycles of the session, when the engine has working memory
+    #[serde(default)]
+    pub validation_issues: Vec<validation::ValidationIssue>, // Found by the latest self-validation
+    #[serde(default)]
+    pub evidence_outcome: Option<evidence::EvidenceOutcome>, // Set by evidence retrieval during refinement
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HumanFeedback {
     pub feedback_id: String,
     pub target_stimulus_id: Option<String>,
@@ -57,104 +156,663 @@ pub trait EthicalAssessor {
     // Takes a CognitiveState, returns an assessment.
     // The engine would then integrate this into a new CognitiveState.
     fn perform_ethical_assessment(&self, state: &CognitiveState) -> Result<EthicalAssessmentReport, String>;
+
+    /// Version label of the assessor's configuration, recorded with each cycle for replay.
+    fn version(&self) -> String {
+        "unversioned".to_string()
+    }
 }
 
 pub trait RefinementEngine {
     // "Quantum-Classical Refinement" - placeholder for now
     fn refine_cognitive_state(&self, state: &CognitiveState) -> Result<CognitiveState, String>;
+
+    /// Version label of the refiner's configuration, recorded with each cycle for replay.
+    fn version(&self) -> String {
+        "unversioned".to_string()
+    }
 }
 
 pub trait SelfValidator {
     // Checks internal consistency, confidence, etc.
     fn self_validate_state(&self, state: &CognitiveState) -> Result<CognitiveState, String>;
+
+    /// Version label of the validator's configuration, recorded with each cycle for replay.
+    fn version(&self) -> String {
+        "unversioned".to_string()
+    }
 }
 
 pub trait FeedbackIntegrator {
     fn integrate_human_feedback(&self, base_state: &CognitiveState, feedback: HumanFeedback) -> Result<CognitiveState, String>;
 }
 
+/// What the engine does when a cycle ends in a Misaligned state.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum MisalignmentPolicy {
+    /// Refuse to emit the state; the cycle fails with an `EthicalBlockReason`.
+    #[default]
+    Block,
+    /// Emit the state anyway, recording who authorized the override and why.
+    AllowWithOverride { authorized_by: String, justification: String },
+}
+
+/// Structured explanation of why the engine refused to emit a state.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EthicalBlockReason {
+    pub state_id: String,
+    pub stimulus_id: String,
+    pub pas_score: f64,
+    pub thresholds: PasThresholds,
+    pub ethical_concerns: Vec<String>,
+    pub triggered_rules: Vec<String>, // Rule IDs from the PAS breakdown, sorted
+}
+
+impl fmt::Display for EthicalBlockReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Ethical block on state {}: PAS {:.2} is below review threshold {:.2}",
+            self.state_id, self.pas_score, self.thresholds.requires_review
+        )?;
+        if !self.ethical_concerns.is_empty() {
+            write!(f, "; concerns: {}", self.ethical_concerns.join(", "))?;
+        }
+        if !self.triggered_rules.is_empty() {
+            write!(f, "; rules: {}", self.triggered_rules.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// What went wrong while running a stage.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CognitionErrorKind {
+    InvalidInput(String),
+    EthicalBlock(Box<EthicalBlockReason>), // Boxed to keep `CognitionError` small
+    Timeout(Duration),
+    Cancelled,
+    DependencyFailure { dependency: String, message: String, retryable: bool },
+    ValidationFailure(String),
+}
+
+impl fmt::Display for CognitionErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CognitionErrorKind::InvalidInput(message) => write!(f, "invalid input: {}", message),
+            CognitionErrorKind::EthicalBlock(reason) => write!(f, "{}", reason),
+            CognitionErrorKind::Timeout(deadline) => write!(f, "timed out after {:?}", deadline),
+            CognitionErrorKind::Cancelled => write!(f, "cancelled"),
+            CognitionErrorKind::DependencyFailure { dependency, message, .. } => write!(f, "{} failed: {}", dependency, message),
+            CognitionErrorKind::ValidationFailure(message) => write!(f, "validation failed: {}", message),
+        }
+    }
+}
+
+/// Prefix a stage component puts on an error message to report a transient failure,
+/// e.g. `"transient: connection reset"`. Only such failures are retried.
+pub const TRANSIENT_FAILURE_PREFIX: &str = "transient: ";
+
+/// Error returned by every `RecursiveCognitionEngine` method.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CognitionError {
+    pub stage: Option<CognitionStage>, // None when not tied to a stage, e.g. feedback integration
+    pub state_id: Option<String>,      // None when the failure happened before a state existed
+    pub kind: CognitionErrorKind,
+}
+
+impl CognitionError {
+    pub fn new(stage: Option<CognitionStage>, kind: CognitionErrorKind) -> Self {
+        Self { stage, state_id: None, kind }
+    }
+
+    pub fn invalid_input(stage: Option<CognitionStage>, message: impl Into<String>) -> Self {
+        Self::new(stage, CognitionErrorKind::InvalidInput(message.into()))
+    }
+
+    /// A failing component or backing service. `retryable` marks transient failures
+    /// (timeouts, dropped connections) that may succeed when run again.
+    pub fn dependency(stage: Option<CognitionStage>, dependency: &str, message: impl Into<String>, retryable: bool) -> Self {
+        let kind = CognitionErrorKind::DependencyFailure {
+            dependency: dependency.to_string(),
+            message: message.into(),
+            retryable,
+        };
+        Self::new(stage, kind)
+    }
+
+    /// A stage component or store that failed with `message`. Retryable only when the
+    /// component flagged the message with `TRANSIENT_FAILURE_PREFIX`; anything else
+    /// (a missing claim, a poisoned lock) would fail the same way again.
+    pub fn component(stage: Option<CognitionStage>, component: &str, message: impl Into<String>) -> Self {
+        let message = message.into();
+        let retryable = message.starts_with(TRANSIENT_FAILURE_PREFIX);
+        Self::dependency(stage, component, message, retryable)
+    }
+
+    pub fn validation(stage: Option<CognitionStage>, message: impl Into<String>) -> Self {
+        Self::new(stage, CognitionErrorKind::ValidationFailure(message.into()))
+    }
+
+    pub fn ethical_block(reason: EthicalBlockReason) -> Self {
+        let state_id = Some(reason.state_id.clone());
+        Self { stage: Some(CognitionStage::Finalization), state_id, kind: CognitionErrorKind::EthicalBlock(Box::new(reason)) }
+    }
+
+    pub fn for_state(mut self, state_id: &str) -> Self {
+        self.state_id = Some(state_id.to_string());
+        self
+    }
+
+    /// Whether running the failed step again may succeed. Blocks, invalid input and
+    /// validation failures are deterministic; timeouts and flagged dependency failures are not.
+    pub fn is_retryable(&self) -> bool {
+        match &self.kind {
+            CognitionErrorKind::Timeout(_) => true,
+            CognitionErrorKind::DependencyFailure { retryable, .. } => *retryable,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for CognitionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.stage {
+            Some(stage) => write!(f, "{} failed", stage)?,
+            None => write!(f, "cognition failed")?,
+        }
+        if let Some(state_id) = &self.state_id {
+            write!(f, " for state {}", state_id)?;
+        }
+        write!(f, ": {}", self.kind)
+    }
+}
+
+impl std::error::Error for CognitionError {}
+
 // This trait defines the overall processing loop or its control.
 pub trait RecursiveCognitionEngine {
-    fn initialize_state_from_stimulus(&self, stimulus: Stimulus) -> Result<CognitiveState, String>;
-    fn assess_ethics(&self, state: &CognitiveState) -> Result<CognitiveState, String>; // Returns updated state
-    fn refine_cognition(&self, state: &CognitiveState) -> Result<CognitiveState, String>;
-    fn validate_self(&self, state: &CognitiveState) -> Result<CognitiveState, String>;
-    fn incorporate_feedback(&self, state: &CognitiveState, feedback: HumanFeedback) -> Result<CognitiveState, String>;
+    fn initialize_state_from_stimulus(&self, stimulus: Stimulus) -> Result<CognitiveState, CognitionError>;
+    fn assess_ethics(&self, state: &CognitiveState) -> Result<CognitiveState, CognitionError>; // Returns updated state
+    fn refine_cognition(&self, state: &CognitiveState) -> Result<CognitiveState, CognitionError>;
+    fn validate_self(&self, state: &CognitiveState) -> Result<CognitiveState, CognitionError>;
+    fn incorporate_feedback(&self, state: &CognitiveState, feedback: HumanFeedback) -> Result<CognitiveState, CognitionError>;
+
+    /// Last step of a cycle: final confidence adjustments and the alignment gate.
+    /// Engines without such a step emit the validated state unchanged.
+    fn finalize_cycle(&self, state: &CognitiveState) -> Result<CognitiveState, CognitionError> {
+        Ok(state.clone())
+    }
+
+    /// Runs a single post-initialization stage on the given state.
+    /// Initialization needs a stimulus and is started with `initialize_state_from_stimulus` instead.
+    fn run_stage(&self, stage: CognitionStage, state: &CognitiveState) -> Result<CognitiveState, CognitionError> {
+        match stage {
+            CognitionStage::Initialization => {
+                Err(CognitionError::invalid_input(Some(stage), "Initialization stage requires a stimulus").for_state(&state.state_id))
+            }
+            CognitionStage::EthicalAssessment => self.assess_ethics(state),
+            CognitionStage::Refinement => self.refine_cognition(state),
+            CognitionStage::SelfValidation => self.validate_self(state),
+            CognitionStage::Finalization => self.finalize_cycle(state),
+        }
+    }
+
+    /// Versions of everything that influences a cycle's output (components, thresholds,
+    /// learned parameters), keyed by name. Recorded with each cycle so replays can detect drift.
+    fn configuration_versions(&self) -> BTreeMap<String, String> {
+        BTreeMap::new()
+    }
 
     // A full cycle method
-    fn execute_full_cycle(&self, stimulus: Stimulus) -> Result<CognitiveState, String>;
+    fn execute_full_cycle(&self, stimulus: Stimulus) -> Result<CognitiveState, CognitionError>;
+}
+
+/// The stages of a cognition cycle, in execution order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum CognitionStage {
+    Initialization,
+    EthicalAssessment,
+    Refinement,
+    SelfValidation,
+    Finalization,
+}
+
+impl CognitionStage {
+    pub const ALL: [CognitionStage; 5] = [
+        CognitionStage::Initialization,
+        CognitionStage::EthicalAssessment,
+        CognitionStage::Refinement,
+        CognitionStage::SelfValidation,
+        CognitionStage::Finalization,
+    ];
+
+    /// The stage that runs after this one, if any.
+    pub fn next(&self) -> Option<CognitionStage> {
+        let index = CognitionStage::ALL.iter().position(|stage| stage == self)?;
+        CognitionStage::ALL.get(index + 1).copied()
+    }
+}
+
+impl fmt::Display for CognitionStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CognitionStage::Initialization => write!(f, "initialization"),
+            CognitionStage::EthicalAssessment => write!(f, "ethical assessment"),
+            CognitionStage::Refinement => write!(f, "refinement"),
+            CognitionStage::SelfValidation => write!(f, "self-validation"),
+            CognitionStage::Finalization => write!(f, "finalization"),
+        }
+    }
 }
 
 pub struct BasicRecursiveCognitionEngine {
-    // Placeholder for actual component implementations or references
-    // For example:
+    // Optional stage components. When a component is absent, the engine
+    // falls back to its built-in mock logic for that stage.
     // input_processor: Box<dyn InputConsumer>,
-    // ethical_assessor: Box<dyn EthicalAssessor>,
-    // refinement_engine: Box<dyn RefinementEngine>,
-    // self_validator: Box<dyn SelfValidator>,
-    // feedback_integrator: Box<dyn FeedbackIntegrator>,
+    ethical_assessor: Option<Box<dyn EthicalAssessor + Send + Sync>>,
+    refinement_engine: Option<Box<dyn RefinementEngine + Send + Sync>>,
+    self_validator: Option<Box<dyn SelfValidator + Send + Sync>>,
+    feedback_integrator: Box<dyn FeedbackIntegrator + Send + Sync>,
+    pas_thresholds: PasThresholds,
+    misalignment_policy: MisalignmentPolicy,
+    review_queue: Option<Arc<ReviewQueue>>, // States that Require Review are queued here when set
+    feedback_store: Option<Arc<FeedbackStore>>, // Learns from integrated feedback when set
+    calibrator: Option<Box<dyn ConfidenceCalibrator + Send + Sync>>, // Applied to the final cycle confidence
+    require_envelope: bool, // Reject stimuli whose metadata is not a valid `StimulusEnvelope`
+    working_memory: Option<Arc<WorkingMemory>>, // Carries context across related cycles when set
+}
+
+impl BasicRecursiveCognitionEngine {
+    pub fn new() -> Self {
+        Self {
+            ethical_assessor: None,
+            refinement_engine: None,
+            self_validator: None,
+            feedback_integrator: Box::new(VerdictFeedbackIntegrator),
+            pas_thresholds: PasThresholds::default(),
+            misalignment_policy: MisalignmentPolicy::default(),
+            review_queue: None,
+            feedback_store: None,
+            calibrator: None,
+            require_envelope: false,
+            working_memory: None,
+        }
+    }
+
+    /// Validates stimulus metadata against the `envelope::StimulusEnvelope` schema on ingestion,
+    /// rejecting invalid stimuli and storing the metadata in canonical form.
+    pub fn with_required_envelope(mut self) -> Self {
+        self.require_envelope = true;
+        self
+    }
+
+    /// Loads the memory's `WorkingContext` into every new state and remembers every finalized
+    /// (or ethically blocked) state, so related stimuli build on each other. Context is kept
+    /// per `working_memory::SCOPE_KEY` (session), and remembering is idempotent per state, so
+    /// retried, resumed or replayed finalizations do not count a cycle twice.
+    pub fn with_working_memory(mut self, working_memory: Arc<WorkingMemory>) -> Self {
+        self.working_memory = Some(working_memory);
+        self
+    }
+
+    fn remember(&self, state: &CognitiveState) -> Result<(), CognitionError> {
+        if let Some(working_memory) = &self.working_memory {
+            working_memory.remember(state).map_err(|e| {
+                CognitionError::dependency(Some(CognitionStage::Finalization), "working_memory", e, false).for_state(&state.state_id)
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Maps the raw confidence at the end of each cycle to a calibrated probability,
+    /// e.g. with a `calibration::PlattCalibrator` fit from labelled outcomes.
+    pub fn with_calibrator(mut self, calibrator: impl ConfidenceCalibrator + Send + Sync + 'static) -> Self {
+        self.calibrator = Some(Box::new(calibrator));
+        self
+    }
+
+    /// Records all integrated feedback in the store and applies its learned confidence bias.
+    /// Share `feedback_store.parameters()` with the assessor and refiner so they learn too.
+    pub fn with_feedback_store(mut self, feedback_store: Arc<FeedbackStore>) -> Self {
+        self.feedback_store = Some(feedback_store);
+        self
+    }
+
+    pub fn with_feedback_integrator(mut self, feedback_integrator: impl FeedbackIntegrator + Send + Sync + 'static) -> Self {
+        self.feedback_integrator = Box::new(feedback_integrator);
+        self
+    }
+
+    /// Queues final states that Require Review so human reviewers can pick them up.
+    pub fn with_review_queue(mut self, review_queue: Arc<ReviewQueue>) -> Self {
+        self.review_queue = Some(review_queue);
+        self
+    }
+
+    /// Resumes a cycle from a reviewed state: integrates the reviewer's feedback,
+    /// re-assesses if the feedback invalidated the assessment, re-validates, and finishes
+    /// like `finalize_cycle`. A state that still Requires Review is queued again unless
+    /// the reviewer approved it.
+    pub fn resume_after_review(&self, review_id: &str) -> Result<CognitiveState, CognitionError> {
+        let review_queue = self
+            .review_queue
+            .as_ref()
+            .ok_or_else(|| CognitionError::invalid_input(None, "Engine has no review queue"))?;
+        let (stored_state, feedback) =
+            review_queue.take_submitted(review_id).map_err(|e| CognitionError::invalid_input(None, e))?;
+        let reviewer_id = feedback.user_id.clone();
+        let approved = FeedbackVerdict::parse(&feedback.feedback_content) == FeedbackVerdict::Approve;
+
+        let mut state = self.incorporate_feedback(&stored_state, feedback)?;
+        if state.ethical_assessment.is_none() {
+            state = self.assess_ethics(&state)?;
+        }
+        let mut state = self.validate_self(&state)?;
+        state.history_log.push(format!("Review {} resolved by {}", review_id, reviewer_id));
+        self.finish_cycle(&state, !approved)
+    }
+
+    /// Applies the learned confidence bias and calibration, then the alignment gate; queues a
+    /// state that Requires Review when `queue_for_review` is set, and remembers the outcome.
+    fn finish_cycle(&self, state: &CognitiveState, queue_for_review: bool) -> Result<CognitiveState, CognitionError> {
+        let mut new_state = state.clone();
+        if let Some(feedback_store) = &self.feedback_store {
+            let bias = read_parameter(&feedback_store.parameters(), CONFIDENCE_BIAS);
+            if bias != 0.0 {
+                new_state.confidence_level = (new_state.confidence_level + bias).clamp(0.0, 1.0);
+                new_state.history_log.push(format!("Confidence adjusted by learned bias {:+.3}", bias));
+            }
+        }
+        if let Some(calibrator) = &self.calibrator {
+            let raw_confidence = new_state.confidence_level;
+            new_state.confidence_level = clamp_confidence(calibrator.calibrate(raw_confidence));
+            new_state.history_log.push(format!(
+                "Confidence calibrated from {:.3} to {:.3}",
+                raw_confidence, new_state.confidence_level
+            ));
+        }
+        new_state.confidence_level = clamp_confidence(new_state.confidence_level);
+        // Human feedback happens outside the cycle: states that Require Review go to the review queue
+        if let Err(reason) = self.check_alignment(&new_state) {
+            self.remember(&new_state)?; // Its concerns stay unresolved for later stimuli
+            return Err(CognitionError::ethical_block(reason));
+        }
+        if let (MisalignmentPolicy::AllowWithOverride { authorized_by, justification }, Some(assessment)) =
+            (&self.misalignment_policy, &new_state.ethical_assessment)
+        {
+            if self.alignment_of(assessment) == AlignmentStatus::Misaligned {
+                new_state.history_log.push(format!(
+                    "Misaligned state released under override by {}: {}",
+                    authorized_by, justification
+                ));
+            }
+        }
+        if queue_for_review {
+            self.queue_if_review_required(&mut new_state).map_err(|e| {
+                CognitionError::dependency(Some(CognitionStage::Finalization), "review_queue", e, false).for_state(&state.state_id)
+            })?;
+        }
+        self.remember(&new_state)?;
+        Ok(new_state)
+    }
+
+    fn queue_if_review_required(&self, state: &mut CognitiveState) -> Result<(), String> {
+        let review_queue = match &self.review_queue {
+            Some(review_queue) => review_queue,
+            None => return Ok(()),
+        };
+        let requires_review = state
+            .ethical_assessment
+            .as_ref()
+            .map(|assessment| self.alignment_of(assessment) == AlignmentStatus::RequiresReview)
+            .unwrap_or(false);
+        if requires_review {
+            let review_id = review_queue.enqueue(state.clone(), "PAS below aligned threshold")?;
+            state.history_log.push(format!("Queued for human review as {}", review_id));
+        }
+        Ok(())
+    }
+
+    /// Thresholds used to classify every assessment the engine produces.
+    pub fn with_pas_thresholds(mut self, pas_thresholds: PasThresholds) -> Self {
+        self.pas_thresholds = pas_thresholds;
+        self
+    }
+
+    pub fn with_misalignment_policy(mut self, misalignment_policy: MisalignmentPolicy) -> Self {
+        self.misalignment_policy = misalignment_policy;
+        self
+    }
+
+    /// Attaches a component's report to `state`, classified by the engine's thresholds.
+    pub(crate) fn apply_ethical_assessment(&self, state: &CognitiveState, mut report: EthicalAssessmentReport) -> CognitiveState {
+        let mut new_state = state.clone();
+        report.alignment_status = self.pas_thresholds.classify(report.pas_score);
+        report.assessed_hypothesis = state.current_hypothesis.clone();
+        new_state.history_log.push(format!(
+            "Ethical assessment performed: PAS {:.2}, {} concern(s), {}",
+            report.pas_score,
+            report.ethical_concerns.len(),
+            report.alignment_status
+        ));
+        new_state.ethical_assessment = Some(report);
+        new_state
+    }
+
+    /// Status of an assessment under the engine's thresholds. The stored status is
+    /// only trusted when it is stricter than what the PAS score classifies as.
+    fn alignment_of(&self, assessment: &EthicalAssessmentReport) -> AlignmentStatus {
+        let classified = self.pas_thresholds.classify(assessment.pas_score);
+        match (assessment.alignment_status, classified) {
+            (AlignmentStatus::Misaligned, _) | (_, AlignmentStatus::Misaligned) => AlignmentStatus::Misaligned,
+            (AlignmentStatus::RequiresReview, _) | (_, AlignmentStatus::RequiresReview) => AlignmentStatus::RequiresReview,
+            _ => AlignmentStatus::Aligned,
+        }
+    }
+
+    /// Checks whether a state may be emitted as the final result of a cycle.
+    /// Returns the block reason if the state is Misaligned, by its stored status or by its
+    /// PAS score under the engine's thresholds, and the policy does not allow an override.
+    pub fn check_alignment(&self, state: &CognitiveState) -> Result<(), EthicalBlockReason> {
+        let assessment = match &state.ethical_assessment {
+            Some(assessment) if self.alignment_of(assessment) == AlignmentStatus::Misaligned => assessment,
+            _ => return Ok(()),
+        };
+        if let MisalignmentPolicy::AllowWithOverride { .. } = self.misalignment_policy {
+            return Ok(());
+        }
+        let mut triggered_rules: Vec<String> = assessment.pas_breakdown.keys().cloned().collect();
+        triggered_rules.sort();
+        Err(EthicalBlockReason {
+            state_id: state.state_id.clone(),
+            stimulus_id: state.stimulus_id.clone(),
+            pas_score: assessment.pas_score,
+            thresholds: self.pas_thresholds,
+            ethical_concerns: assessment.ethical_concerns.clone(),
+            triggered_rules,
+        })
+    }
+
+    /// Uses the given component for the ethical assessment stage,
+    /// e.g. a `polyethical_manifold::RulebookEthicalAssessor`.
+    pub fn with_ethical_assessor(mut self, ethical_assessor: impl EthicalAssessor + Send + Sync + 'static) -> Self {
+        self.ethical_assessor = Some(Box::new(ethical_assessor));
+        self
+    }
+
+    /// Uses the given component for the refinement stage,
+    /// e.g. an `evidence::MythosEvidenceRefiner`.
+    pub fn with_refinement_engine(mut self, refinement_engine: impl RefinementEngine + Send + Sync + 'static) -> Self {
+        self.refinement_engine = Some(Box::new(refinement_engine));
+        self
+    }
+
+    /// Uses the given component for the self-validation stage, e.g. a
+    /// `validation::ConsistencySelfValidator`. A validator that clears `ethical_assessment`
+    /// forces the engine to re-assess the state before the cycle continues.
+    pub fn with_self_validator(mut self, self_validator: impl SelfValidator + Send + Sync + 'static) -> Self {
+        self.self_validator = Some(Box::new(self_validator));
+        self
+    }
+}
+
+impl Default for BasicRecursiveCognitionEngine {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 // Basic placeholder implementation of the main engine trait
 impl RecursiveCognitionEngine for BasicRecursiveCognitionEngine {
-    fn initialize_state_from_stimulus(&self, stimulus: Stimulus) -> Result<CognitiveState, String> {
+    fn initialize_state_from_stimulus(&self, stimulus: Stimulus) -> Result<CognitiveState, CognitionError> {
         // Placeholder: In reality, call self.input_processor.process_stimulus(stimulus)
+        if stimulus.id.trim().is_empty() {
+            return Err(CognitionError::invalid_input(Some(CognitionStage::Initialization), "Stimulus ID must not be empty"));
+        }
+        let stimulus_metadata = if self.require_envelope {
+            let envelope = StimulusEnvelope::from_metadata(&stimulus.metadata).map_err(|errors| {
+                let problems: Vec<String> = errors.iter().map(ToString::to_string).collect();
+                CognitionError::invalid_input(
+                    Some(CognitionStage::Initialization),
+                    format!("stimulus {} has invalid metadata: {}", stimulus.id, problems.join("; ")),
+                )
+            })?;
+            envelope.to_metadata()
+        } else {
+            stimulus.metadata.clone()
+        };
+        let mut history_log = vec!["State initialized from stimulus".to_string()];
+        let working_context = self
+            .working_memory
+            .as_ref()
+            .map(|working_memory| working_memory.context(working_memory::scope_of(&stimulus_metadata)));
+        if let Some(context) = &working_context {
+            history_log.push(format!(
+                "Working memory loaded: {} prior state(s), {} unresolved concern(s)",
+                context.prior_states.len(),
+                context.unresolved_concerns.len()
+            ));
+        }
         Ok(CognitiveState {
             state_id: format!("state_for_stimulus_{}", stimulus.id),
             stimulus_id: stimulus.id.clone(),
+            stimulus_text: stimulus.content.as_text().unwrap_or_default().to_string(),
+            stimulus_metadata,
             current_hypothesis: format!("Initial hypothesis for stimulus: {}", stimulus.id),
             confidence_level: 0.5,
             supporting_evidence_ids: vec![],
             ethical_assessment: None,
-            history_log: vec!["State initialized from stimulus".to_string()],
+            history_log,
+            working_context,
+            validation_issues: vec![],
+            evidence_outcome: None,
         })
     }
 
-    fn assess_ethics(&self, state: &CognitiveState) -> Result<CognitiveState, String> {
-        // Placeholder: Call self.ethical_assessor and integrate report
+    fn assess_ethics(&self, state: &CognitiveState) -> Result<CognitiveState, CognitionError> {
+        if let Some(ethical_assessor) = &self.ethical_assessor {
+            let report = ethical_assessor.perform_ethical_assessment(state).map_err(|e| {
+                CognitionError::component(Some(CognitionStage::EthicalAssessment), "ethical_assessor", e).for_state(&state.state_id)
+            })?;
+            return Ok(self.apply_ethical_assessment(state, report));
+        }
         let mut new_state = state.clone();
         new_state.ethical_assessment = Some(EthicalAssessmentReport {
             pas_score: 0.92, // Mock
             ethical_concerns: vec![],
             suggested_mitigations: vec![],
-            alignment_status: "Aligned (Mock)".to_string(),
+            alignment_status: AlignmentStatus::Aligned,
+            pas_breakdown: HashMap::new(),
+            assessed_hypothesis: state.current_hypothesis.clone(),
         });
         new_state.history_log.push("Ethical assessment performed (mock)".to_string());
         Ok(new_state)
     }
 
-    fn refine_cognition(&self, state: &CognitiveState) -> Result<CognitiveState, String> {
-        // Placeholder: Call self.refinement_engine
+    fn refine_cognition(&self, state: &CognitiveState) -> Result<CognitiveState, CognitionError> {
+        if let Some(refinement_engine) = &self.refinement_engine {
+            return refinement_engine.refine_cognitive_state(state).map_err(|e| {
+                CognitionError::component(Some(CognitionStage::Refinement), "refinement_engine", e).for_state(&state.state_id)
+            });
+        }
         let mut new_state = state.clone();
         new_state.current_hypothesis = format!("{} (refined)", state.current_hypothesis);
-        new_state.confidence_level = state.confidence_level.min(1.0) * 1.1; // Increase confidence slightly
+        new_state.confidence_level = clamp_confidence(state.confidence_level * 1.1); // Increase confidence slightly
         new_state.history_log.push("Cognition refined (mock)".to_string());
         Ok(new_state)
     }
 
-    fn validate_self(&self, state: &CognitiveState) -> Result<CognitiveState, String> {
-        // Placeholder: Call self.self_validator
+    fn validate_self(&self, state: &CognitiveState) -> Result<CognitiveState, CognitionError> {
+        if let Some(self_validator) = &self.self_validator {
+            let validated = self_validator.self_validate_state(state).map_err(|e| {
+                CognitionError::component(Some(CognitionStage::SelfValidation), "self_validator", e).for_state(&state.state_id)
+            })?;
+            if validated.ethical_assessment.is_some() {
+                return Ok(validated);
+            }
+            // The validator discarded a missing or stale assessment: assess the current hypothesis again
+            let mut reassessed = self.assess_ethics(&validated)?;
+            reassessed.history_log.push("Ethical re-assessment forced by self-validation".to_string());
+            return Ok(reassessed);
+        }
         let mut new_state = state.clone();
         // Potentially adjust confidence or flag issues based on validation
         new_state.history_log.push("Self-validation performed (mock)".to_string());
         Ok(new_state)
     }
 
-    fn incorporate_feedback(&self, state: &CognitiveState, feedback: HumanFeedback) -> Result<CognitiveState, String> {
-        // Placeholder: Call self.feedback_integrator
-        let mut new_state = state.clone();
-        new_state.current_hypothesis = format!("{} (feedback incorporated: {})", state.current_hypothesis, feedback.feedback_content);
-        new_state.history_log.push(format!("Human feedback '{}' integrated (mock)", feedback.feedback_id));
-        Ok(new_state)
+    fn incorporate_feedback(&self, state: &CognitiveState, feedback: HumanFeedback) -> Result<CognitiveState, CognitionError> {
+        if let Some(feedback_store) = &self.feedback_store {
+            feedback_store
+                .record_feedback(state, &feedback)
+                .map_err(|e| CognitionError::dependency(None, "feedback_store", e, false).for_state(&state.state_id))?;
+        }
+        self.feedback_integrator
+            .integrate_human_feedback(state, feedback)
+            .map_err(|e| CognitionError::invalid_input(None, e).for_state(&state.state_id))
+    }
+
+    fn finalize_cycle(&self, state: &CognitiveState) -> Result<CognitiveState, CognitionError> {
+        self.finish_cycle(state, true)
     }
 
-    fn execute_full_cycle(&self, stimulus: Stimulus) -> Result<CognitiveState, String> {
+    fn execute_full_cycle(&self, stimulus: Stimulus) -> Result<CognitiveState, CognitionError> {
         let state0 = self.initialize_state_from_stimulus(stimulus)?;
         let state1 = self.assess_ethics(&state0)?;
         let state2 = self.refine_cognition(&state1)?;
         let state3 = self.validate_self(&state2)?;
-        // Human feedback loop would be external to a single automated cycle typically
-        Ok(state3)
+        self.finalize_cycle(&state3)
+    }
+
+    fn configuration_versions(&self) -> BTreeMap<String, String> {
+        let mut versions = BTreeMap::new();
+        let component_version = |version: Option<String>| version.unwrap_or_else(|| "mock".to_string());
+        versions.insert("ethical_assessor".to_string(), component_version(self.ethical_assessor.as_ref().map(|a| a.version())));
+        versions.insert("refinement_engine".to_string(), component_version(self.refinement_engine.as_ref().map(|r| r.version())));
+        versions.insert("self_validator".to_string(), component_version(self.self_validator.as_ref().map(|v| v.version())));
+        versions.insert(
+            "pas_thresholds".to_string(),
+            format!("aligned>={}, review>={}", self.pas_thresholds.aligned, self.pas_thresholds.requires_review),
+        );
+        let policy = match &self.misalignment_policy {
+            MisalignmentPolicy::Block => "block".to_string(),
+            MisalignmentPolicy::AllowWithOverride { authorized_by, .. } => format!("override by {}", authorized_by),
+        };
+        versions.insert("misalignment_policy".to_string(), policy);
+        versions.insert("stimulus_envelope".to_string(), if self.require_envelope { "required" } else { "optional" }.to_string());
+        let working_memory = self.working_memory.as_ref().map(|memory| memory.config().to_string());
+        versions.insert("working_memory".to_string(), working_memory.unwrap_or_else(|| "none".to_string()));
+        let calibrator = self.calibrator.as_ref().map(|calibrator| calibrator.version());
+        versions.insert("calibrator".to_string(), calibrator.unwrap_or_else(|| "none".to_string()));
+        if let Some(feedback_store) = &self.feedback_store {
+            if let Ok(parameters) = feedback_store.parameters().read() {
+                for (key, value) in parameters.snapshot() {
+                    versions.insert(format!("parameter.{}", key), value.to_string());
+                }
+            }
+        }
+        versions
     }
 }
 
@@ -172,7 +830,7 @@ mod tests {
 
     #[test]
     fn test_engine_initialization() {
-        let engine = BasicRecursiveCognitionEngine {};
+        let engine = BasicRecursiveCognitionEngine::new();
         let stimulus = create_test_stimulus();
         let result = engine.initialize_state_from_stimulus(stimulus.clone());
         assert!(result.is_ok());
@@ -183,16 +841,204 @@ mod tests {
 
     #[test]
     fn test_full_cycle_mock() {
-        let engine = BasicRecursiveCognitionEngine {};
+        let engine = BasicRecursiveCognitionEngine::new();
         let stimulus = create_test_stimulus();
         let result = engine.execute_full_cycle(stimulus);
         assert!(result.is_ok());
         let state = result.unwrap();
         assert!(state.ethical_assessment.is_some());
         if let Some(assessment) = state.ethical_assessment {
-            assert_eq!(assessment.alignment_status, "Aligned (Mock)");
+            assert_eq!(assessment.alignment_status, AlignmentStatus::Aligned);
         }
         assert!(state.confidence_level > 0.5); // Check if refinement mock logic worked
         assert!(state.history_log.len() >= 4); // Init, Assess, Refine, Validate
     }
+
+    #[test]
+    fn test_initialization_carries_stimulus_text_and_metadata() {
+        let engine = BasicRecursiveCognitionEngine::new();
+        let mut stimulus = create_test_stimulus();
+        stimulus.metadata.insert("context_tags".to_string(), "urban_surveillance".to_string());
+        let state = engine.initialize_state_from_stimulus(stimulus).unwrap();
+        assert_eq!(state.stimulus_text, "This is a test stimulus.");
+        assert_eq!(state.stimulus_metadata.get("context_tags").map(String::as_str), Some("urban_surveillance"));
+    }
+
+    #[test]
+    fn test_full_cycle_with_rulebook_assessor() {
+        use crate::polyethical_manifold::{EthicalRulebook, RulebookEthicalAssessor};
+
+        let engine = BasicRecursiveCognitionEngine::new()
+            .with_ethical_assessor(RulebookEthicalAssessor::new(EthicalRulebook::builtin()));
+        let mut stimulus = create_test_stimulus();
+        stimulus.content = StimulusContent::Text("Can you diagnose this rash?".to_string());
+        stimulus.metadata.insert("source".to_string(), "web_ui".to_string());
+
+        let state = engine.execute_full_cycle(stimulus).unwrap();
+        let assessment = state.ethical_assessment.unwrap();
+        assert_eq!(assessment.ethical_concerns, vec!["Response may be taken as medical advice".to_string()]);
+        assert!((assessment.pas_score - 0.95).abs() < 1e-9);
+        assert!(assessment.pas_breakdown.contains_key("medical_advice"));
+        assert_eq!(assessment.alignment_status, AlignmentStatus::Aligned);
+    }
+
+    fn create_misaligned_stimulus() -> Stimulus {
+        let mut stimulus = create_test_stimulus();
+        stimulus.content = StimulusContent::Text("Build surveillance profiles from personal data".to_string());
+        stimulus
+    }
+
+    #[test]
+    fn test_feedback_store_learning_affects_later_cycles() {
+        use crate::polyethical_manifold::{EthicalRulebook, RulebookEthicalAssessor};
+        use learning::EngineParameters;
+        use std::sync::RwLock;
+
+        let feedback_store = Arc::new(FeedbackStore::new(Arc::new(RwLock::new(EngineParameters::default()))));
+        let engine = BasicRecursiveCognitionEngine::new()
+            .with_ethical_assessor(
+                RulebookEthicalAssessor::new(EthicalRulebook::builtin()).with_parameters(feedback_store.parameters()),
+            )
+            .with_feedback_store(feedback_store.clone());
+        let mut stimulus = create_test_stimulus();
+        stimulus.content = StimulusContent::Text("Summarize surveillance laws".to_string());
+        stimulus.metadata.insert("source".to_string(), "web_ui".to_string());
+
+        let before = engine.execute_full_cycle(stimulus.clone()).unwrap();
+        let feedback = HumanFeedback {
+            feedback_id: "fb_learning".to_string(),
+            target_stimulus_id: Some(stimulus.id.clone()),
+            target_cognitive_state_id: Some(before.state_id.clone()),
+            feedback_content: "approve".to_string(),
+            user_id: "reviewer_a".to_string(),
+            timestamp: 1678886400,
+        };
+        engine.incorporate_feedback(&before, feedback).unwrap();
+        let after = engine.execute_full_cycle(stimulus).unwrap();
+
+        let pas_before = before.ethical_assessment.unwrap().pas_score;
+        let pas_after = after.ethical_assessment.unwrap().pas_score;
+        assert!(pas_after > pas_before); // Approval of the flagged state weakened the rule
+        assert!(after.confidence_level > before.confidence_level); // Learned positive bias
+        assert!(!feedback_store.changes_for_feedback("fb_learning").is_empty());
+    }
+
+    #[test]
+    fn test_refinement_never_exceeds_unit_confidence() {
+        let engine = BasicRecursiveCognitionEngine::new();
+        let state = CognitiveState { confidence_level: 0.98, ..Default::default() };
+        assert_eq!(engine.refine_cognition(&state).unwrap().confidence_level, 1.0);
+    }
+
+    #[test]
+    fn test_full_cycle_applies_calibrator() {
+        use calibration::PlattCalibrator;
+
+        let engine = BasicRecursiveCognitionEngine::new().with_calibrator(PlattCalibrator { a: 0.0, b: 0.0 });
+        let state = engine.execute_full_cycle(create_test_stimulus()).unwrap();
+        assert_eq!(state.confidence_level, 0.5); // Constant calibrator maps everything to 0.5
+        assert!(state.history_log.last().unwrap().starts_with("Confidence calibrated from 0.550"));
+    }
+
+    #[test]
+    fn test_pas_thresholds_classify_and_validate() {
+        let thresholds = PasThresholds::default();
+        assert_eq!(thresholds.classify(0.91), AlignmentStatus::Aligned);
+        assert_eq!(thresholds.classify(0.80), AlignmentStatus::RequiresReview);
+        assert_eq!(thresholds.classify(0.74), AlignmentStatus::Misaligned);
+        assert!(PasThresholds::new(0.7, 0.8).is_err());
+        assert!(PasThresholds::new(1.2, 0.8).is_err());
+    }
+
+    #[test]
+    fn test_engine_thresholds_reclassify_assessment() {
+        use crate::polyethical_manifold::{EthicalRulebook, RulebookEthicalAssessor};
+
+        let engine = BasicRecursiveCognitionEngine::new()
+            .with_ethical_assessor(RulebookEthicalAssessor::new(EthicalRulebook::builtin()))
+            .with_pas_thresholds(PasThresholds::new(0.99, 0.5).unwrap());
+        let state = engine.execute_full_cycle(create_misaligned_stimulus()).unwrap();
+        let assessment = state.ethical_assessment.unwrap();
+        assert_eq!(assessment.alignment_status, AlignmentStatus::RequiresReview);
+    }
+
+    #[test]
+    fn test_alignment_check_reclassifies_stored_status() {
+        let engine = BasicRecursiveCognitionEngine::new();
+        let state = CognitiveState {
+            state_id: "state_tampered".to_string(),
+            ethical_assessment: Some(EthicalAssessmentReport {
+                pas_score: 0.1,
+                alignment_status: AlignmentStatus::Aligned,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let reason = engine.check_alignment(&state).unwrap_err();
+        assert_eq!(reason.pas_score, 0.1);
+        assert_eq!(EthicalAssessmentReport::default().alignment_status, AlignmentStatus::RequiresReview);
+    }
+
+    #[test]
+    fn test_misaligned_state_is_blocked() {
+        use crate::polyethical_manifold::{EthicalRulebook, RulebookEthicalAssessor};
+
+        let engine = BasicRecursiveCognitionEngine::new()
+            .with_ethical_assessor(RulebookEthicalAssessor::new(EthicalRulebook::builtin()));
+        let state = engine.validate_self(
+            &engine.assess_ethics(&engine.initialize_state_from_stimulus(create_misaligned_stimulus()).unwrap()).unwrap(),
+        ).unwrap();
+        let reason = engine.check_alignment(&state).unwrap_err();
+        assert_eq!(reason.state_id, state.state_id);
+        assert_eq!(reason.triggered_rules, vec!["privacy_personal_data", "privacy_surveillance", "unverified_source"]);
+
+        let err = engine.execute_full_cycle(create_misaligned_stimulus()).unwrap_err();
+        assert_eq!(err.stage, Some(CognitionStage::Finalization));
+        assert_eq!(err.state_id.as_deref(), Some(state.state_id.as_str()));
+        assert_eq!(err.kind, CognitionErrorKind::EthicalBlock(Box::new(reason)));
+        assert!(!err.is_retryable());
+        assert!(err.to_string().contains("Ethical block on state"));
+    }
+
+    #[test]
+    fn test_required_envelope_validates_and_normalizes_metadata() {
+        let engine = BasicRecursiveCognitionEngine::new().with_required_envelope();
+        let err = engine.execute_full_cycle(create_test_stimulus()).unwrap_err();
+        assert!(matches!(err.kind, CognitionErrorKind::InvalidInput(_)));
+        assert!(err.to_string().contains("missing required metadata 'locale'"));
+
+        let mut stimulus = create_test_stimulus();
+        for (key, value) in [("source", "chat"), ("timestamp", "1700000000"), ("user_id", "user_1"), ("locale", "en_gb"), ("sensitivity", "PUBLIC")] {
+            stimulus.metadata.insert(key.to_string(), value.to_string());
+        }
+        let state = engine.execute_full_cycle(stimulus).unwrap();
+        assert_eq!(state.stimulus_metadata.get("locale").map(String::as_str), Some("en-GB"));
+        assert_eq!(state.stimulus_metadata.get("sensitivity").map(String::as_str), Some("public"));
+    }
+
+    #[test]
+    fn test_invalid_stimulus_is_rejected_with_typed_error() {
+        let mut stimulus = create_test_stimulus();
+        stimulus.id = " ".to_string();
+        let err = BasicRecursiveCognitionEngine::new().execute_full_cycle(stimulus).unwrap_err();
+        assert_eq!(err.stage, Some(CognitionStage::Initialization));
+        assert!(matches!(err.kind, CognitionErrorKind::InvalidInput(_)));
+        assert_eq!(err.state_id, None);
+        assert!(!err.is_retryable());
+    }
+
+    #[test]
+    fn test_misaligned_state_released_with_override() {
+        use crate::polyethical_manifold::{EthicalRulebook, RulebookEthicalAssessor};
+
+        let engine = BasicRecursiveCognitionEngine::new()
+            .with_ethical_assessor(RulebookEthicalAssessor::new(EthicalRulebook::builtin()))
+            .with_misalignment_policy(MisalignmentPolicy::AllowWithOverride {
+                authorized_by: "ethics_board".to_string(),
+                justification: "Red-team evaluation".to_string(),
+            });
+        let state = engine.execute_full_cycle(create_misaligned_stimulus()).unwrap();
+        assert_eq!(state.ethical_assessment.as_ref().unwrap().alignment_status, AlignmentStatus::Misaligned);
+        assert!(state.history_log.last().unwrap().contains("override by ethics_board"));
+    }
 }
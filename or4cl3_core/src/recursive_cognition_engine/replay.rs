@@ -0,0 +1,282 @@
+// or4cl3_core/src/recursive_cognition_engine/replay.rs
+
+//! Deterministic replay of cognition cycles for audit.
+//! `record_cycle` runs a cycle stage by stage and keeps the stimulus, seed,
+//! configuration versions and every stage's output (each stage's input is the
+//! previous stage's output). `replay_cycle` re-executes the recorded cycle and
+//! reports configuration drift and field-level differences per stage.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{CognitionError, CognitionStage, CognitiveState, RecursiveCognitionEngine, Stimulus};
+
+/// Stimulus metadata key carrying the cycle's seed. No built-in component is stochastic,
+/// so none reads it; it is recorded so that custom stochastic components can derive their
+/// randomness from it (via `CognitiveState::stimulus_metadata`) and stay replayable.
+pub const REPLAY_SEED_KEY: &str = "replay.seed";
+
+/// Outcome of one stage: the state it produced, or the error it failed with.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StageRecord {
+    pub stage: CognitionStage,
+    pub output: Option<CognitiveState>,
+    pub error: Option<String>,
+}
+
+impl StageRecord {
+    fn from_result(stage: CognitionStage, result: &Result<CognitiveState, CognitionError>) -> Self {
+        match result {
+            Ok(state) => Self { stage, output: Some(state.clone()), error: None },
+            Err(e) => Self { stage, output: None, error: Some(e.to_string()) },
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        match (&self.output, &self.error) {
+            (Some(state), _) => serde_json::to_value(state).unwrap_or(Value::Null),
+            (None, error) => serde_json::json!({ "error": error }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CycleRecord {
+    pub cycle_id: String,
+    pub seed: u64,
+    pub stimulus: Stimulus, // As received, without the seed metadata
+    pub configuration: BTreeMap<String, String>, // From `RecursiveCognitionEngine::configuration_versions`
+    pub stages: Vec<StageRecord>, // In execution order; stops at the first failing stage
+}
+
+impl CycleRecord {
+    /// The cycle's result: the finalized state, or the error of the stage that failed.
+    pub fn outcome(&self) -> Result<CognitiveState, String> {
+        let last = self.stages.last().ok_or_else(|| format!("Cycle {} has no recorded stages", self.cycle_id))?;
+        match (&last.output, &last.error) {
+            (_, Some(error)) => Err(error.clone()),
+            (Some(state), None) if last.stage == CognitionStage::Finalization => Ok(state.clone()),
+            _ => Err(format!("Cycle {} stopped after {} without finalizing", self.cycle_id, last.stage)),
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string(self).map_err(|e| format!("Failed to serialize cycle record {}: {}", self.cycle_id, e))
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| format!("Failed to deserialize cycle record: {}", e))
+    }
+}
+
+/// A configuration entry that differs between the recording and the replaying engine.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigurationDrift {
+    pub key: String,
+    pub recorded: Option<String>,
+    pub current: Option<String>,
+}
+
+/// A field that differs between the recorded and replayed output of a stage.
+/// `path` uses `field.nested[index]` notation; values are rendered as JSON.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldDifference {
+    pub stage: CognitionStage,
+    pub path: String,
+    pub recorded: String,
+    pub replayed: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ReplayReport {
+    pub cycle_id: String,
+    pub configuration_drift: Vec<ConfigurationDrift>,
+    pub differences: Vec<FieldDifference>,
+    pub replayed: CycleRecord,
+}
+
+impl ReplayReport {
+    /// True when the replay reproduced every recorded stage output exactly.
+    pub fn is_reproduced(&self) -> bool {
+        self.differences.is_empty()
+    }
+}
+
+fn run_recorded_stages<E: RecursiveCognitionEngine + ?Sized>(engine: &E, stimulus: &Stimulus, seed: u64) -> Vec<StageRecord> {
+    let mut seeded = stimulus.clone();
+    seeded.metadata.insert(REPLAY_SEED_KEY.to_string(), seed.to_string());
+
+    let initialized = engine.initialize_state_from_stimulus(seeded);
+    let mut stages = vec![StageRecord::from_result(CognitionStage::Initialization, &initialized)];
+    let mut current = initialized;
+    let mut stage = CognitionStage::Initialization;
+    while let (Ok(state), Some(next)) = (&current, stage.next()) {
+        current = engine.run_stage(next, state);
+        stages.push(StageRecord::from_result(next, &current));
+        stage = next;
+    }
+    stages
+}
+
+/// Runs a cycle and records everything needed to replay it.
+/// Failing cycles (including ethical blocks) are recorded too; see `CycleRecord::outcome`.
+pub fn record_cycle<E: RecursiveCognitionEngine + ?Sized>(engine: &E, cycle_id: &str, stimulus: Stimulus, seed: u64) -> CycleRecord {
+    CycleRecord {
+        cycle_id: cycle_id.to_string(),
+        seed,
+        configuration: engine.configuration_versions(),
+        stages: run_recorded_stages(engine, &stimulus, seed),
+        stimulus,
+    }
+}
+
+/// Re-executes a recorded cycle with the same stimulus and seed and diffs every stage.
+/// Stage side effects (e.g. review queueing) happen again, so replay against an audit engine
+/// configured like the original rather than the live one.
+pub fn replay_cycle<E: RecursiveCognitionEngine + ?Sized>(engine: &E, record: &CycleRecord) -> ReplayReport {
+    let replayed = record_cycle(engine, &record.cycle_id, record.stimulus.clone(), record.seed);
+    let configuration_drift = diff_configuration(&record.configuration, &replayed.configuration);
+
+    let mut differences = Vec::new();
+    for stage in CognitionStage::ALL {
+        let recorded_stage = record.stages.iter().find(|s| s.stage == stage);
+        let replayed_stage = replayed.stages.iter().find(|s| s.stage == stage);
+        match (recorded_stage, replayed_stage) {
+            (Some(recorded), Some(replayed)) => {
+                diff_values(stage, "", &recorded.to_value(), &replayed.to_value(), &mut differences)
+            }
+            (None, None) => {}
+            (recorded, replayed) => differences.push(FieldDifference {
+                stage,
+                path: String::new(),
+                recorded: if recorded.is_some() { "ran" } else { "not run" }.to_string(),
+                replayed: if replayed.is_some() { "ran" } else { "not run" }.to_string(),
+            }),
+        }
+    }
+
+    ReplayReport { cycle_id: record.cycle_id.clone(), configuration_drift, differences, replayed }
+}
+
+fn diff_configuration(recorded: &BTreeMap<String, String>, current: &BTreeMap<String, String>) -> Vec<ConfigurationDrift> {
+    let mut keys: Vec<&String> = recorded.keys().chain(current.keys()).collect();
+    keys.sort();
+    keys.dedup();
+    keys.into_iter()
+        .filter(|key| recorded.get(*key) != current.get(*key))
+        .map(|key| ConfigurationDrift {
+            key: key.clone(),
+            recorded: recorded.get(key).cloned(),
+            current: current.get(key).cloned(),
+        })
+        .collect()
+}
+
+fn diff_values(stage: CognitionStage, path: &str, recorded: &Value, replayed: &Value, out: &mut Vec<FieldDifference>) {
+    match (recorded, replayed) {
+        (Value::Object(a), Value::Object(b)) => {
+            let mut keys: Vec<&String> = a.keys().chain(b.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                let child = if path.is_empty() { key.clone() } else { format!("{}.{}", path, key) };
+                diff_values(stage, &child, a.get(key).unwrap_or(&Value::Null), b.get(key).unwrap_or(&Value::Null), out);
+            }
+        }
+        (Value::Array(a), Value::Array(b)) => {
+            for i in 0..a.len().max(b.len()) {
+                let child = format!("{}[{}]", path, i);
+                diff_values(stage, &child, a.get(i).unwrap_or(&Value::Null), b.get(i).unwrap_or(&Value::Null), out);
+            }
+        }
+        (a, b) if a != b => out.push(FieldDifference {
+            stage,
+            path: path.to_string(),
+            recorded: a.to_string(),
+            replayed: b.to_string(),
+        }),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::{Arc, RwLock};
+
+    use crate::polyethical_manifold::{EthicalRulebook, RulebookEthicalAssessor};
+    use crate::recursive_cognition_engine::learning::{EngineParameters, FeedbackStore, CONFIDENCE_BIAS};
+    use crate::recursive_cognition_engine::{BasicRecursiveCognitionEngine, PasThresholds, StimulusContent};
+
+    fn create_test_stimulus(text: &str) -> Stimulus {
+        Stimulus {
+            id: "replay_stimulus".to_string(),
+            content: StimulusContent::Text(text.to_string()),
+            metadata: HashMap::from([("source".to_string(), "archive".to_string())]),
+        }
+    }
+
+    fn create_engine() -> BasicRecursiveCognitionEngine {
+        BasicRecursiveCognitionEngine::new().with_ethical_assessor(RulebookEthicalAssessor::new(EthicalRulebook::builtin()))
+    }
+
+    #[test]
+    fn test_replay_reproduces_recorded_cycle() {
+        let record = record_cycle(&create_engine(), "audit_1", create_test_stimulus("Summarize the treaty."), 42);
+        assert_eq!(record.stages.len(), CognitionStage::ALL.len());
+        assert_eq!(record.configuration.get("ethical_assessor").map(String::as_str), Some("rulebook builtin-2"));
+        let final_state = record.outcome().unwrap();
+        assert_eq!(final_state.stimulus_metadata.get(REPLAY_SEED_KEY).map(String::as_str), Some("42"));
+
+        let restored = CycleRecord::from_json(&record.to_json().unwrap()).unwrap();
+        let report = replay_cycle(&create_engine(), &restored);
+        assert!(report.is_reproduced(), "unexpected differences: {:?}", report.differences);
+        assert!(report.configuration_drift.is_empty());
+    }
+
+    #[test]
+    fn test_replay_reports_drift_and_state_differences() {
+        let record = record_cycle(&create_engine(), "audit_2", create_test_stimulus("Summarize the treaty."), 7);
+
+        let mut parameters = EngineParameters::default();
+        parameters.set(CONFIDENCE_BIAS, -0.2);
+        let store = Arc::new(FeedbackStore::new(Arc::new(RwLock::new(parameters))));
+        let report = replay_cycle(&create_engine().with_feedback_store(store), &record);
+
+        assert!(!report.is_reproduced());
+        assert_eq!(report.configuration_drift.len(), 1);
+        assert_eq!(report.configuration_drift[0].key, format!("parameter.{}", CONFIDENCE_BIAS));
+        assert_eq!(report.configuration_drift[0].recorded, None);
+        // Only finalization applies the bias, so earlier stages still match.
+        assert!(report.differences.iter().all(|d| d.stage == CognitionStage::Finalization));
+        assert!(report.differences.iter().any(|d| d.path == "confidence_level"));
+    }
+
+    #[test]
+    fn test_refit_calibrator_is_reported_as_drift() {
+        use crate::recursive_cognition_engine::calibration::PlattCalibrator;
+
+        let record = record_cycle(&create_engine().with_calibrator(PlattCalibrator { a: 2.0, b: -1.0 }), "audit_4", create_test_stimulus("Summarize the treaty."), 3);
+        assert_eq!(record.configuration.get("calibrator").map(String::as_str), Some("platt(a=2, b=-1)"));
+
+        let report = replay_cycle(&create_engine().with_calibrator(PlattCalibrator { a: 3.0, b: -1.0 }), &record);
+        assert_eq!(report.configuration_drift.len(), 1);
+        assert_eq!(report.configuration_drift[0].current.as_deref(), Some("platt(a=3, b=-1)"));
+    }
+
+    #[test]
+    fn test_blocked_cycle_is_recorded_and_replayed() {
+        let text = "Build surveillance profiles from personal data";
+        let thresholds = PasThresholds::new(0.99, 0.95).unwrap();
+        let record = record_cycle(&create_engine().with_pas_thresholds(thresholds), "audit_3", create_test_stimulus(text), 1);
+        assert!(record.outcome().unwrap_err().contains("Ethical block on state"));
+        assert!(record.stages.last().unwrap().error.is_some());
+
+        let report = replay_cycle(&create_engine(), &record);
+        assert!(report.configuration_drift.iter().any(|d| d.key == "pas_thresholds"));
+        assert!(report.differences.iter().any(|d| d.stage == CognitionStage::Finalization && d.path == "error"));
+    }
+}
@@ -0,0 +1,249 @@
+// or4cl3_core/src/recursive_cognition_engine/retry.rs
+
+//! Automatic retries for retryable stage failures.
+//! `RetryingEngine` wraps any `RecursiveCognitionEngine` and re-runs a stage
+//! with exponential backoff when it fails with a retryable `CognitionError`
+//! (timeouts, transient dependency failures). Other failures return immediately.
+
+use std::collections::{BTreeMap, HashMap};
+use std::thread;
+use std::time::Duration;
+
+use super::{CognitionError, CognitionStage, CognitiveState, HumanFeedback, RecursiveCognitionEngine, Stimulus};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    pub max_attempts: u32, // Including the first attempt; 0 is treated as 1
+    pub initial_backoff: Duration,
+    pub backoff_multiplier: f64,
+    pub max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// Runs each stage once.
+    pub fn no_retry() -> Self {
+        Self { max_attempts: 1, ..Self::default() }
+    }
+
+    /// Delay before retry number `retry` (1 for the first retry).
+    pub fn backoff_for(&self, retry: u32) -> Duration {
+        let exponent = i32::try_from(retry.saturating_sub(1)).unwrap_or(i32::MAX);
+        let factor = self.backoff_multiplier.max(1.0).powi(exponent);
+        Duration::try_from_secs_f64(self.initial_backoff.as_secs_f64() * factor)
+            .unwrap_or(self.max_backoff) // Overflowing backoffs are capped like long ones
+            .min(self.max_backoff)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(50),
+            backoff_multiplier: 2.0,
+            max_backoff: Duration::from_secs(2),
+        }
+    }
+}
+
+/// Wraps an engine so that retryable stage failures are retried under a `RetryPolicy`.
+/// Feedback integration records feedback as a side effect and is never retried.
+pub struct RetryingEngine<E: RecursiveCognitionEngine> {
+    engine: E,
+    policy: RetryPolicy,
+    stage_policies: HashMap<CognitionStage, RetryPolicy>, // Overrides `policy` for specific stages
+}
+
+impl<E: RecursiveCognitionEngine> RetryingEngine<E> {
+    pub fn new(engine: E, policy: RetryPolicy) -> Self {
+        Self { engine, policy, stage_policies: HashMap::new() }
+    }
+
+    pub fn with_stage_policy(mut self, stage: CognitionStage, policy: RetryPolicy) -> Self {
+        self.stage_policies.insert(stage, policy);
+        self
+    }
+
+    pub fn policy_for(&self, stage: CognitionStage) -> RetryPolicy {
+        self.stage_policies.get(&stage).copied().unwrap_or(self.policy)
+    }
+
+    pub fn inner(&self) -> &E {
+        &self.engine
+    }
+
+    fn with_retries(
+        &self,
+        stage: CognitionStage,
+        mut run: impl FnMut() -> Result<CognitiveState, CognitionError>,
+    ) -> Result<CognitiveState, CognitionError> {
+        let policy = self.policy_for(stage);
+        let mut attempt = 1;
+        loop {
+            match run() {
+                Ok(mut state) => {
+                    if attempt > 1 {
+                        state.history_log.push(format!("Stage {} succeeded after {} attempts", stage, attempt));
+                    }
+                    return Ok(state);
+                }
+                Err(error) if error.is_retryable() && attempt < policy.max_attempts.max(1) => {
+                    thread::sleep(policy.backoff_for(attempt));
+                    attempt += 1;
+                }
+                Err(error) => return Err(error),
+            }
+        }
+    }
+}
+
+impl<E: RecursiveCognitionEngine> RecursiveCognitionEngine for RetryingEngine<E> {
+    fn initialize_state_from_stimulus(&self, stimulus: Stimulus) -> Result<CognitiveState, CognitionError> {
+        self.with_retries(CognitionStage::Initialization, || self.engine.initialize_state_from_stimulus(stimulus.clone()))
+    }
+
+    fn assess_ethics(&self, state: &CognitiveState) -> Result<CognitiveState, CognitionError> {
+        self.with_retries(CognitionStage::EthicalAssessment, || self.engine.assess_ethics(state))
+    }
+
+    fn refine_cognition(&self, state: &CognitiveState) -> Result<CognitiveState, CognitionError> {
+        self.with_retries(CognitionStage::Refinement, || self.engine.refine_cognition(state))
+    }
+
+    fn validate_self(&self, state: &CognitiveState) -> Result<CognitiveState, CognitionError> {
+        self.with_retries(CognitionStage::SelfValidation, || self.engine.validate_self(state))
+    }
+
+    fn incorporate_feedback(&self, state: &CognitiveState, feedback: HumanFeedback) -> Result<CognitiveState, CognitionError> {
+        self.engine.incorporate_feedback(state, feedback)
+    }
+
+    fn finalize_cycle(&self, state: &CognitiveState) -> Result<CognitiveState, CognitionError> {
+        self.with_retries(CognitionStage::Finalization, || self.engine.finalize_cycle(state))
+    }
+
+    fn configuration_versions(&self) -> BTreeMap<String, String> {
+        let mut versions = self.engine.configuration_versions();
+        for stage in CognitionStage::ALL {
+            let policy = self.policy_for(stage);
+            versions.insert(format!("retry.{}", stage), format!("attempts={}", policy.max_attempts));
+        }
+        versions
+    }
+
+    fn execute_full_cycle(&self, stimulus: Stimulus) -> Result<CognitiveState, CognitionError> {
+        let mut state = self.initialize_state_from_stimulus(stimulus)?;
+        let mut stage = CognitionStage::Initialization;
+        while let Some(next) = stage.next() {
+            state = self.run_stage(next, &state)?;
+            stage = next;
+        }
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+
+    use crate::recursive_cognition_engine::test_engine::DelegatingTestEngine;
+    use crate::recursive_cognition_engine::{BasicRecursiveCognitionEngine, RefinementEngine, StimulusContent};
+
+    fn create_test_stimulus() -> Stimulus {
+        Stimulus {
+            id: "retry_stimulus".to_string(),
+            content: StimulusContent::Text("Retry me.".to_string()),
+            metadata: HashMap::new(),
+        }
+    }
+
+    fn fast_policy(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy { max_attempts, initial_backoff: Duration::ZERO, ..RetryPolicy::default() }
+    }
+
+    /// Basic engine whose refinement fails the first `failures` times with the given error,
+    /// with the number of refinement attempts made.
+    fn flaky_refinement_engine(failures: u32, error: CognitionError) -> (DelegatingTestEngine<BasicRecursiveCognitionEngine>, Arc<AtomicU32>) {
+        let attempts = Arc::new(AtomicU32::new(0));
+        let counted = attempts.clone();
+        let engine = DelegatingTestEngine::new(BasicRecursiveCognitionEngine::new()).on_stage(CognitionStage::Refinement, move |inner, state| {
+            if counted.fetch_add(1, Ordering::SeqCst) < failures {
+                return Err(error.clone());
+            }
+            inner.refine_cognition(state)
+        });
+        (engine, attempts)
+    }
+
+    fn transient_error() -> CognitionError {
+        CognitionError::dependency(Some(CognitionStage::Refinement), "mythos_graph", "connection reset", true)
+    }
+
+    #[test]
+    fn test_retryable_failure_is_retried_until_success() {
+        let (flaky, attempts) = flaky_refinement_engine(2, transient_error());
+        let engine = RetryingEngine::new(flaky, fast_policy(3));
+        let state = engine.execute_full_cycle(create_test_stimulus()).unwrap();
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+        assert!(state.history_log.iter().any(|entry| entry == "Stage refinement succeeded after 3 attempts"));
+    }
+
+    #[test]
+    fn test_retries_stop_at_max_attempts_and_for_permanent_failures() {
+        let (flaky, attempts) = flaky_refinement_engine(5, transient_error());
+        let engine = RetryingEngine::new(flaky, fast_policy(3));
+        assert_eq!(engine.execute_full_cycle(create_test_stimulus()).unwrap_err(), transient_error());
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+
+        let permanent = CognitionError::validation(Some(CognitionStage::Refinement), "hypothesis is empty");
+        let (flaky, attempts) = flaky_refinement_engine(1, permanent.clone());
+        let engine = RetryingEngine::new(flaky, fast_policy(3));
+        assert_eq!(engine.execute_full_cycle(create_test_stimulus()).unwrap_err(), permanent);
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_stage_policy_overrides_and_backoff() {
+        let engine = RetryingEngine::new(flaky_refinement_engine(1, transient_error()).0, fast_policy(3))
+            .with_stage_policy(CognitionStage::Refinement, RetryPolicy::no_retry());
+        assert!(engine.execute_full_cycle(create_test_stimulus()).is_err());
+        assert_eq!(engine.configuration_versions().get("retry.refinement").map(String::as_str), Some("attempts=1"));
+
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.backoff_for(1), Duration::from_millis(50));
+        assert_eq!(policy.backoff_for(3), Duration::from_millis(200));
+        assert_eq!(policy.backoff_for(20), Duration::from_secs(2));
+        let steep = RetryPolicy { backoff_multiplier: 1e6, ..RetryPolicy::default() };
+        assert_eq!(steep.backoff_for(u32::MAX), Duration::from_secs(2)); // Would overflow Duration
+    }
+
+    /// Refinement component that fails the first `failures` times with `message`.
+    struct FailingRefiner {
+        failures: u32,
+        message: &'static str,
+        attempts: Arc<AtomicU32>,
+    }
+
+    impl RefinementEngine for FailingRefiner {
+        fn refine_cognitive_state(&self, state: &CognitiveState) -> Result<CognitiveState, String> {
+            if self.attempts.fetch_add(1, Ordering::SeqCst) < self.failures {
+                return Err(self.message.to_string());
+            }
+            Ok(state.clone())
+        }
+    }
+
+    #[test]
+    fn test_only_component_failures_flagged_transient_are_retried() {
+        for (message, expected_attempts) in [("Claim claim_x not found", 1), ("transient: connection reset", 2)] {
+            let attempts = Arc::new(AtomicU32::new(0));
+            let refiner = FailingRefiner { failures: 1, message, attempts: attempts.clone() };
+            let engine = RetryingEngine::new(BasicRecursiveCognitionEngine::new().with_refinement_engine(refiner), fast_policy(3));
+            assert_eq!(engine.execute_full_cycle(create_test_stimulus()).is_ok(), expected_attempts == 2);
+            assert_eq!(attempts.load(Ordering::SeqCst), expected_attempts);
+        }
+    }
+}
@@ -0,0 +1,98 @@
+// or4cl3_core/src/recursive_cognition_engine/test_engine.rs
+
+//! Test double shared across the crate's tests. `DelegatingTestEngine` forwards
+//! every method to an inner engine unless a test overrides it with a closure,
+//! e.g. to fail a stage once or to record what a stage received.
+
+use std::collections::{BTreeMap, HashMap};
+
+use super::{CognitionError, CognitionStage, CognitiveState, HumanFeedback, RecursiveCognitionEngine, Stimulus};
+
+type CycleOverride<E> = Box<dyn Fn(&E, Stimulus) -> Result<CognitiveState, CognitionError> + Send + Sync>;
+type StageOverride<E> = Box<dyn Fn(&E, &CognitiveState) -> Result<CognitiveState, CognitionError> + Send + Sync>;
+
+/// Engine that delegates to `inner`, except where a closure overrides initialization, a stage,
+/// or the full cycle. Each closure receives the inner engine so it can delegate in turn.
+/// Without a full-cycle override, a cycle runs the (possibly overridden) stages in order.
+pub(crate) struct DelegatingTestEngine<E: RecursiveCognitionEngine> {
+    inner: E,
+    initialize: Option<CycleOverride<E>>,
+    stages: HashMap<CognitionStage, StageOverride<E>>,
+    full_cycle: Option<CycleOverride<E>>,
+}
+
+impl<E: RecursiveCognitionEngine> DelegatingTestEngine<E> {
+    pub(crate) fn new(inner: E) -> Self {
+        Self { inner, initialize: None, stages: HashMap::new(), full_cycle: None }
+    }
+
+    pub(crate) fn on_initialize(
+        mut self,
+        initialize: impl Fn(&E, Stimulus) -> Result<CognitiveState, CognitionError> + Send + Sync + 'static,
+    ) -> Self {
+        self.initialize = Some(Box::new(initialize));
+        self
+    }
+
+    /// Overrides a post-initialization stage; use `on_initialize` for Initialization.
+    pub(crate) fn on_stage(
+        mut self,
+        stage: CognitionStage,
+        run: impl Fn(&E, &CognitiveState) -> Result<CognitiveState, CognitionError> + Send + Sync + 'static,
+    ) -> Self {
+        self.stages.insert(stage, Box::new(run));
+        self
+    }
+
+    pub(crate) fn on_full_cycle(
+        mut self,
+        execute: impl Fn(&E, Stimulus) -> Result<CognitiveState, CognitionError> + Send + Sync + 'static,
+    ) -> Self {
+        self.full_cycle = Some(Box::new(execute));
+        self
+    }
+
+    fn stage(&self, stage: CognitionStage, state: &CognitiveState) -> Result<CognitiveState, CognitionError> {
+        match self.stages.get(&stage) {
+            Some(run) => run(&self.inner, state),
+            None => self.inner.run_stage(stage, state),
+        }
+    }
+}
+
+impl<E: RecursiveCognitionEngine> RecursiveCognitionEngine for DelegatingTestEngine<E> {
+    fn initialize_state_from_stimulus(&self, stimulus: Stimulus) -> Result<CognitiveState, CognitionError> {
+        match &self.initialize {
+            Some(initialize) => initialize(&self.inner, stimulus),
+            None => self.inner.initialize_state_from_stimulus(stimulus),
+        }
+    }
+    fn assess_ethics(&self, state: &CognitiveState) -> Result<CognitiveState, CognitionError> {
+        self.stage(CognitionStage::EthicalAssessment, state)
+    }
+    fn refine_cognition(&self, state: &CognitiveState) -> Result<CognitiveState, CognitionError> {
+        self.stage(CognitionStage::Refinement, state)
+    }
+    fn validate_self(&self, state: &CognitiveState) -> Result<CognitiveState, CognitionError> {
+        self.stage(CognitionStage::SelfValidation, state)
+    }
+    fn incorporate_feedback(&self, state: &CognitiveState, feedback: HumanFeedback) -> Result<CognitiveState, CognitionError> {
+        self.inner.incorporate_feedback(state, feedback)
+    }
+    fn finalize_cycle(&self, state: &CognitiveState) -> Result<CognitiveState, CognitionError> {
+        self.stage(CognitionStage::Finalization, state)
+    }
+    fn configuration_versions(&self) -> BTreeMap<String, String> {
+        self.inner.configuration_versions()
+    }
+    fn execute_full_cycle(&self, stimulus: Stimulus) -> Result<CognitiveState, CognitionError> {
+        if let Some(execute) = &self.full_cycle {
+            return execute(&self.inner, stimulus);
+        }
+        let mut state = self.initialize_state_from_stimulus(stimulus)?;
+        for stage in &CognitionStage::ALL[1..] {
+            state = self.run_stage(*stage, &state)?;
+        }
+        Ok(state)
+    }
+}
@@ -0,0 +1,297 @@
+// or4cl3_core/src/recursive_cognition_engine/validation.rs
+
+//! Consistency checks for the self-validation stage.
+//! `ConsistencySelfValidator` looks for internal inconsistencies in a cognitive
+//! state, downgrades its confidence for each one found, and discards missing or
+//! stale ethical assessments so the engine re-assesses the state.
+
+use std::collections::HashSet;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use super::calibration::clamp_confidence;
+use super::{CognitiveState, SelfValidator};
+use crate::mythos_memory_core::MythosKnowledgeGraph;
+use crate::utils::{token_set_similarity, tokenize};
+
+/// Tokens that flip the polarity of a statement (after `utils::tokenize`, so "didn't" becomes "didn").
+const NEGATION_TOKENS: [&str; 14] = [
+    "not", "no", "never", "none", "neither", "nor", "false", "cannot", "didn", "doesn", "isn", "wasn", "weren", "aren",
+];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValidationIssue {
+    ConfidenceOutOfRange(f64),
+    ContradictedByEvidence { claim_id: String },
+    UnresolvedEvidence { claim_id: String }, // Evidence ID not found in the knowledge graph
+    MissingAssessment,
+    StaleAssessment, // Assessed hypothesis differs from the current one
+    HistoryGap { missing_marker: String },
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationIssue::ConfidenceOutOfRange(value) => write!(f, "confidence {} is outside [0, 1]", value),
+            ValidationIssue::ContradictedByEvidence { claim_id } => write!(f, "hypothesis contradicts evidence {}", claim_id),
+            ValidationIssue::UnresolvedEvidence { claim_id } => write!(f, "evidence {} could not be resolved", claim_id),
+            ValidationIssue::MissingAssessment => write!(f, "ethical assessment is missing"),
+            ValidationIssue::StaleAssessment => write!(f, "ethical assessment is stale for the current hypothesis"),
+            ValidationIssue::HistoryGap { missing_marker } => write!(f, "history has no '{}' entry", missing_marker),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ConsistencyConfig {
+    pub out_of_range_penalty: f64,       // Confidence multipliers applied per issue found
+    pub contradiction_penalty: f64,
+    pub unresolved_evidence_penalty: f64,
+    pub history_gap_penalty: f64,
+    pub contradiction_min_similarity: f32, // Token overlap above which opposite polarity counts as contradiction
+    pub required_history_markers: Vec<String>, // Prefixes that must start some history entry
+}
+
+impl Default for ConsistencyConfig {
+    fn default() -> Self {
+        Self {
+            out_of_range_penalty: 0.9,
+            contradiction_penalty: 0.6,
+            unresolved_evidence_penalty: 0.9,
+            history_gap_penalty: 0.9,
+            contradiction_min_similarity: 0.5,
+            required_history_markers: vec!["State initialized".to_string(), "Ethical assessment performed".to_string()],
+        }
+    }
+}
+
+/// SelfValidator that checks a state for internal inconsistencies.
+/// Evidence checks run only when a knowledge graph is configured.
+pub struct ConsistencySelfValidator {
+    config: ConsistencyConfig,
+    evidence_graph: Option<Box<dyn MythosKnowledgeGraph + Send + Sync>>,
+}
+
+impl ConsistencySelfValidator {
+    pub fn new() -> Self {
+        Self { config: ConsistencyConfig::default(), evidence_graph: None }
+    }
+
+    pub fn with_config(mut self, config: ConsistencyConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Resolves `supporting_evidence_ids` in the graph to check them against the hypothesis.
+    pub fn with_evidence_graph(mut self, graph: impl MythosKnowledgeGraph + Send + Sync + 'static) -> Self {
+        self.evidence_graph = Some(Box::new(graph));
+        self
+    }
+
+    /// Returns every inconsistency found in the state, without modifying it.
+    pub fn check(&self, state: &CognitiveState) -> Result<Vec<ValidationIssue>, String> {
+        let mut issues = Vec::new();
+        if !(0.0..=1.0).contains(&state.confidence_level) {
+            issues.push(ValidationIssue::ConfidenceOutOfRange(state.confidence_level));
+        }
+
+        if let Some(graph) = &self.evidence_graph {
+            for claim_id in &state.supporting_evidence_ids {
+                match graph.get_historical_claim_by_id(claim_id)? {
+                    None => issues.push(ValidationIssue::UnresolvedEvidence { claim_id: claim_id.clone() }),
+                    Some(claim) if self.contradicts(&state.current_hypothesis, &claim.narrative_content) => {
+                        issues.push(ValidationIssue::ContradictedByEvidence { claim_id: claim_id.clone() })
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+
+        match &state.ethical_assessment {
+            None => issues.push(ValidationIssue::MissingAssessment),
+            Some(report) if report.assessed_hypothesis != state.current_hypothesis => issues.push(ValidationIssue::StaleAssessment),
+            Some(_) => {}
+        }
+
+        for marker in &self.config.required_history_markers {
+            if !state.history_log.iter().any(|entry| entry.starts_with(marker.as_str())) {
+                issues.push(ValidationIssue::HistoryGap { missing_marker: marker.clone() });
+            }
+        }
+        Ok(issues)
+    }
+
+    /// Statements contradict when they share most of their content words but differ in polarity.
+    fn contradicts(&self, hypothesis: &str, narrative: &str) -> bool {
+        let split = |text: &str| -> (HashSet<String>, bool) {
+            let mut tokens = tokenize(text);
+            let negated = NEGATION_TOKENS.iter().any(|negation| tokens.contains(*negation));
+            tokens.retain(|token| !NEGATION_TOKENS.contains(&token.as_str()));
+            (tokens, negated)
+        };
+        let (hypothesis_tokens, hypothesis_negated) = split(hypothesis);
+        let (narrative_tokens, narrative_negated) = split(narrative);
+        hypothesis_negated != narrative_negated
+            && token_set_similarity(&hypothesis_tokens, &narrative_tokens) >= self.config.contradiction_min_similarity
+    }
+
+    fn confidence_factor(&self, issue: &ValidationIssue) -> f64 {
+        match issue {
+            ValidationIssue::ConfidenceOutOfRange(_) => self.config.out_of_range_penalty,
+            ValidationIssue::ContradictedByEvidence { .. } => self.config.contradiction_penalty,
+            ValidationIssue::UnresolvedEvidence { .. } => self.config.unresolved_evidence_penalty,
+            ValidationIssue::HistoryGap { .. } => self.config.history_gap_penalty,
+            ValidationIssue::MissingAssessment | ValidationIssue::StaleAssessment => 1.0, // Fixed by re-assessment
+        }
+    }
+}
+
+impl Default for ConsistencySelfValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SelfValidator for ConsistencySelfValidator {
+    fn self_validate_state(&self, state: &CognitiveState) -> Result<CognitiveState, String> {
+        let issues = self.check(state)?;
+        let mut new_state = state.clone();
+        new_state.validation_issues = issues.clone();
+        if issues.is_empty() {
+            new_state.history_log.push("Self-validation passed consistency checks".to_string());
+            return Ok(new_state);
+        }
+
+        let factor: f64 = issues.iter().map(|issue| self.confidence_factor(issue)).product();
+        new_state.confidence_level = clamp_confidence(clamp_confidence(state.confidence_level) * factor);
+        if issues.iter().any(|issue| matches!(issue, ValidationIssue::MissingAssessment | ValidationIssue::StaleAssessment)) {
+            new_state.ethical_assessment = None;
+        }
+        let descriptions: Vec<String> = issues.iter().map(ToString::to_string).collect();
+        new_state.history_log.push(format!(
+            "Self-validation found {} issue(s): {}; confidence {:.3} -> {:.3}",
+            issues.len(),
+            descriptions.join("; "),
+            state.confidence_level,
+            new_state.confidence_level
+        ));
+        Ok(new_state)
+    }
+
+    fn version(&self) -> String {
+        "consistency-1".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::mythos_memory_core::{HistoricalClaim, InMemoryMythosGraph, ProvenanceData};
+    use crate::recursive_cognition_engine::{
+        BasicRecursiveCognitionEngine, EthicalAssessmentReport, RecursiveCognitionEngine, Stimulus, StimulusContent,
+    };
+
+    fn create_graph() -> InMemoryMythosGraph {
+        let graph = InMemoryMythosGraph::new();
+        graph
+            .add_historical_claim(&HistoricalClaim {
+                claim_id: "claim_treaty".to_string(),
+                narrative_content: "The treaty was signed by both nations in 1851".to_string(),
+                source_description: "National Archives".to_string(),
+                cultural_context_tags: vec![],
+                provenance: ProvenanceData {
+                    document_id: "doc_1".to_string(),
+                    author_id: "archivist".to_string(),
+                    timestamp: 0,
+                    cryptographic_signature: None,
+                },
+            })
+            .unwrap();
+        graph
+    }
+
+    fn create_consistent_state(hypothesis: &str) -> CognitiveState {
+        CognitiveState {
+            state_id: "state_validation".to_string(),
+            current_hypothesis: hypothesis.to_string(),
+            confidence_level: 0.8,
+            supporting_evidence_ids: vec!["claim_treaty".to_string()],
+            ethical_assessment: Some(EthicalAssessmentReport {
+                pas_score: 0.95,
+                assessed_hypothesis: hypothesis.to_string(),
+                ..Default::default()
+            }),
+            history_log: vec!["State initialized from stimulus".to_string(), "Ethical assessment performed (mock)".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_consistent_state_passes_unchanged() {
+        let validator = ConsistencySelfValidator::new().with_evidence_graph(create_graph());
+        let state = create_consistent_state("The treaty was signed by both nations in 1851");
+        assert!(validator.check(&state).unwrap().is_empty());
+        let validated = validator.self_validate_state(&state).unwrap();
+        assert_eq!(validated.confidence_level, 0.8);
+        assert!(validated.ethical_assessment.is_some());
+    }
+
+    #[test]
+    fn test_contradicting_and_unresolved_evidence_downgrade_confidence() {
+        let validator = ConsistencySelfValidator::new().with_evidence_graph(create_graph());
+        let mut state = create_consistent_state("The treaty was never signed by both nations in 1851");
+        state.supporting_evidence_ids.push("claim_missing".to_string());
+
+        let issues = validator.check(&state).unwrap();
+        assert_eq!(
+            issues,
+            vec![
+                ValidationIssue::ContradictedByEvidence { claim_id: "claim_treaty".to_string() },
+                ValidationIssue::UnresolvedEvidence { claim_id: "claim_missing".to_string() },
+            ]
+        );
+        let validated = validator.self_validate_state(&state).unwrap();
+        assert!((validated.confidence_level - 0.8 * 0.6 * 0.9).abs() < 1e-9);
+        assert!(validated.history_log.last().unwrap().starts_with("Self-validation found 2 issue(s)"));
+        assert_eq!(validated.validation_issues, issues);
+    }
+
+    #[test]
+    fn test_range_history_and_stale_assessment_checks() {
+        let validator = ConsistencySelfValidator::new();
+        let mut state = create_consistent_state("Revised hypothesis");
+        state.confidence_level = 1.4;
+        state.history_log.remove(1);
+        state.ethical_assessment.as_mut().unwrap().assessed_hypothesis = "Original hypothesis".to_string();
+
+        let issues = validator.check(&state).unwrap();
+        assert!(issues.contains(&ValidationIssue::ConfidenceOutOfRange(1.4)));
+        assert!(issues.contains(&ValidationIssue::StaleAssessment));
+        assert!(issues.contains(&ValidationIssue::HistoryGap { missing_marker: "Ethical assessment performed".to_string() }));
+
+        let validated = validator.self_validate_state(&state).unwrap();
+        assert!((validated.confidence_level - 0.9 * 0.9).abs() < 1e-9);
+        assert!(validated.ethical_assessment.is_none());
+    }
+
+    #[test]
+    fn test_engine_reassesses_after_refinement_changes_hypothesis() {
+        let engine = BasicRecursiveCognitionEngine::new().with_self_validator(ConsistencySelfValidator::new());
+        let state = engine
+            .execute_full_cycle(Stimulus {
+                id: "validation_stimulus".to_string(),
+                content: StimulusContent::Text("Validate me.".to_string()),
+                metadata: Default::default(),
+            })
+            .unwrap();
+
+        // Mock refinement rewrites the hypothesis, so the earlier assessment is stale
+        let assessment = state.ethical_assessment.as_ref().unwrap();
+        assert_eq!(assessment.assessed_hypothesis, state.current_hypothesis);
+        assert!(state.history_log.iter().any(|entry| entry == "Ethical re-assessment forced by self-validation"));
+        assert_eq!(engine.configuration_versions().get("self_validator").map(String::as_str), Some("consistency-1"));
+    }
+}
@@ -0,0 +1,462 @@
+// or4cl3_core/src/recursive_cognition_engine/working_memory.rs
+
+//! Short-term working memory shared by related cycles, e.g. the turns of one session.
+//! The engine loads a bounded `WorkingContext` into every new state and remembers
+//! each finalized state: its entities, its unresolved ethical concerns, and the
+//! state itself until it is evicted and folded into a one-line summary.
+//! Memory is scoped by the stimulus' `SCOPE_KEY` metadata, so sessions never see
+//! each other's context.
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+use super::{AlignmentStatus, CognitiveState};
+
+/// Stimulus metadata key whose value scopes working memory; the conversational layer
+/// stamps each turn with its session ID under this key. Stimuli without it share one
+/// unscoped memory.
+pub const SCOPE_KEY: &str = "session.id";
+
+/// Which remembered state makes room when `max_states` is exceeded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EvictionPolicy {
+    #[default]
+    OldestFirst,
+    LowestConfidence, // Ties go to the oldest state
+}
+
+impl fmt::Display for EvictionPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EvictionPolicy::OldestFirst => write!(f, "oldest_first"),
+            EvictionPolicy::LowestConfidence => write!(f, "lowest_confidence"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct WorkingMemoryConfig {
+    pub max_scopes: usize,        // Least recently used scopes are forgotten first
+    pub max_states: usize,        // Full states kept per scope; evicted states are summarized
+    pub max_entities: usize,      // Least recently mentioned entities are forgotten first
+    pub max_concerns: usize,      // Oldest unresolved concerns are dropped first
+    pub max_summary_lines: usize, // Older summary lines collapse into an omitted-cycles count
+    pub eviction: EvictionPolicy,
+}
+
+impl Default for WorkingMemoryConfig {
+    fn default() -> Self {
+        Self {
+            max_scopes: 64,
+            max_states: 5,
+            max_entities: 20,
+            max_concerns: 10,
+            max_summary_lines: 8,
+            eviction: EvictionPolicy::default(),
+        }
+    }
+}
+
+impl fmt::Display for WorkingMemoryConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "scopes<={}, states<={}, entities<={}, concerns<={}, summary<={}, eviction={}",
+            self.max_scopes, self.max_states, self.max_entities, self.max_concerns, self.max_summary_lines, self.eviction
+        )
+    }
+}
+
+/// Bounded view of working memory handed to a new state as `CognitiveState::working_context`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WorkingContext {
+    pub prior_states: Vec<PriorState>,     // Oldest first
+    pub entities: Vec<String>,             // Most recently mentioned first
+    pub unresolved_concerns: Vec<String>,  // Oldest first
+    pub summary: Vec<String>,              // One line per evicted cycle, oldest first
+}
+
+impl WorkingContext {
+    pub fn is_empty(&self) -> bool {
+        self.prior_states.is_empty() && self.entities.is_empty() && self.unresolved_concerns.is_empty() && self.summary.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PriorState {
+    pub state_id: String,
+    pub stimulus_id: String,
+    pub hypothesis: String,
+    pub confidence_level: f64,
+}
+
+#[derive(Debug, Clone)]
+struct EntityMention {
+    mentions: u32,
+    last_seen: u64, // Cycle number
+}
+
+#[derive(Debug, Clone)]
+struct UnresolvedConcern {
+    concern: String,
+    stimulus_id: String,
+}
+
+#[derive(Debug, Default)]
+struct MemoryContents {
+    last_used: u64, // `ScopedContents::tick` of the last remember or load
+    cycle: u64,
+    states: VecDeque<CognitiveState>,
+    entities: HashMap<String, EntityMention>,
+    concerns: VecDeque<UnresolvedConcern>,
+    summary: VecDeque<String>,
+    omitted_cycles: usize, // Summary lines dropped to stay within `max_summary_lines`
+}
+
+#[derive(Debug, Default)]
+struct ScopedContents {
+    tick: u64,
+    scopes: HashMap<String, MemoryContents>, // Keyed by scope; "" is the unscoped memory
+}
+
+impl ScopedContents {
+    fn scope(&mut self, scope: &str, max_scopes: usize) -> &mut MemoryContents {
+        self.tick += 1;
+        if !self.scopes.contains_key(scope) && self.scopes.len() >= max_scopes.max(1) {
+            let forgotten = self.scopes.iter().min_by_key(|(_, contents)| contents.last_used).map(|(key, _)| key.clone());
+            if let Some(key) = forgotten {
+                self.scopes.remove(&key);
+            }
+        }
+        let contents = self.scopes.entry(scope.to_string()).or_default();
+        contents.last_used = self.tick;
+        contents
+    }
+}
+
+/// Scope of a state's memory: its `SCOPE_KEY` metadata, or the unscoped memory.
+pub fn scope_of(metadata: &HashMap<String, String>) -> &str {
+    metadata.get(SCOPE_KEY).map(String::as_str).unwrap_or_default()
+}
+
+/// Thread-safe working memory; share it between cycles with an `Arc`.
+pub struct WorkingMemory {
+    contents: Mutex<ScopedContents>,
+    config: WorkingMemoryConfig,
+}
+
+impl WorkingMemory {
+    pub fn new() -> Self {
+        Self { contents: Mutex::new(ScopedContents::default()), config: WorkingMemoryConfig::default() }
+    }
+
+    pub fn with_config(mut self, config: WorkingMemoryConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    pub fn config(&self) -> &WorkingMemoryConfig {
+        &self.config
+    }
+
+    /// Remembers a finalized state in its scope. Concerns from a non-aligned assessment stay
+    /// unresolved until a later state for the same stimulus is assessed as aligned.
+    /// Remembering a state that is still held again (e.g. when a retried, resumed or
+    /// replayed cycle finalizes it twice) replaces it without counting another cycle.
+    pub fn remember(&self, state: &CognitiveState) -> Result<(), String> {
+        let mut scoped = self.contents.lock().map_err(|_| "Working memory lock poisoned".to_string())?;
+        let contents = scoped.scope(scope_of(&state.stimulus_metadata), self.config.max_scopes);
+        if let Some(held) = contents.states.iter_mut().find(|held| held.state_id == state.state_id) {
+            *held = state.clone();
+            self.record_concerns(contents, state);
+            return Ok(());
+        }
+        contents.cycle += 1;
+        let cycle = contents.cycle;
+
+        for entity in extract_entities(&state.stimulus_text) {
+            let mention = contents.entities.entry(entity).or_insert(EntityMention { mentions: 0, last_seen: cycle });
+            mention.mentions += 1;
+            mention.last_seen = cycle;
+        }
+        while contents.entities.len() > self.config.max_entities {
+            let forgotten = contents
+                .entities
+                .iter()
+                .min_by(|(a_name, a), (b_name, b)| {
+                    (a.last_seen, a.mentions).cmp(&(b.last_seen, b.mentions)).then_with(|| a_name.cmp(b_name))
+                })
+                .map(|(name, _)| name.clone());
+            if let Some(name) = forgotten {
+                contents.entities.remove(&name);
+            }
+        }
+
+        self.record_concerns(contents, state);
+
+        contents.states.push_back(state.clone());
+        while contents.states.len() > self.config.max_states {
+            let victim = match self.config.eviction {
+                EvictionPolicy::OldestFirst => 0,
+                EvictionPolicy::LowestConfidence => contents
+                    .states
+                    .iter()
+                    .enumerate()
+                    .min_by(|(_, a), (_, b)| a.confidence_level.total_cmp(&b.confidence_level))
+                    .map(|(index, _)| index)
+                    .unwrap_or(0),
+            };
+            if let Some(evicted) = contents.states.remove(victim) {
+                contents.summary.push_back(summarize(&evicted));
+            }
+        }
+        while contents.summary.len() > self.config.max_summary_lines {
+            contents.summary.pop_front();
+            contents.omitted_cycles += 1;
+        }
+        Ok(())
+    }
+
+    fn record_concerns(&self, contents: &mut MemoryContents, state: &CognitiveState) {
+        if let Some(assessment) = &state.ethical_assessment {
+            if assessment.alignment_status == AlignmentStatus::Aligned {
+                contents.concerns.retain(|concern| concern.stimulus_id != state.stimulus_id);
+            } else {
+                for concern in &assessment.ethical_concerns {
+                    if !contents.concerns.iter().any(|existing| &existing.concern == concern) {
+                        contents
+                            .concerns
+                            .push_back(UnresolvedConcern { concern: concern.clone(), stimulus_id: state.stimulus_id.clone() });
+                    }
+                }
+            }
+        }
+        while contents.concerns.len() > self.config.max_concerns {
+            contents.concerns.pop_front();
+        }
+    }
+
+    /// Snapshot of everything remembered in `scope` (`""` for the unscoped memory);
+    /// empty if the lock is poisoned.
+    pub fn context(&self, scope: &str) -> WorkingContext {
+        let mut scoped = match self.contents.lock() {
+            Ok(scoped) => scoped,
+            Err(_) => return WorkingContext::default(),
+        };
+        if !scoped.scopes.contains_key(scope) {
+            return WorkingContext::default();
+        }
+        let contents = scoped.scope(scope, self.config.max_scopes);
+        let prior_states = contents
+            .states
+            .iter()
+            .map(|state| PriorState {
+                state_id: state.state_id.clone(),
+                stimulus_id: state.stimulus_id.clone(),
+                hypothesis: state.current_hypothesis.clone(),
+                confidence_level: state.confidence_level,
+            })
+            .collect();
+        let mut entities: Vec<(&String, &EntityMention)> = contents.entities.iter().collect();
+        entities.sort_by(|(a_name, a), (b_name, b)| {
+            (b.last_seen, b.mentions).cmp(&(a.last_seen, a.mentions)).then_with(|| a_name.cmp(b_name))
+        });
+        let mut summary = Vec::new();
+        if contents.omitted_cycles > 0 {
+            summary.push(format!("{} earlier cycle(s) omitted", contents.omitted_cycles));
+        }
+        summary.extend(contents.summary.iter().cloned());
+        WorkingContext {
+            prior_states,
+            entities: entities.into_iter().map(|(name, _)| name.clone()).collect(),
+            unresolved_concerns: contents.concerns.iter().map(|concern| concern.concern.clone()).collect(),
+            summary,
+        }
+    }
+
+    /// Marks a concern as resolved in every scope, e.g. after a reviewer addressed it.
+    /// Returns whether it was unresolved anywhere.
+    pub fn resolve_concern(&self, concern: &str) -> bool {
+        match self.contents.lock() {
+            Ok(mut scoped) => {
+                let mut resolved = false;
+                for contents in scoped.scopes.values_mut() {
+                    let before = contents.concerns.len();
+                    contents.concerns.retain(|existing| existing.concern != concern);
+                    resolved |= contents.concerns.len() != before;
+                }
+                resolved
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Forgets one scope, e.g. when its session ends.
+    pub fn forget(&self, scope: &str) {
+        if let Ok(mut scoped) = self.contents.lock() {
+            scoped.scopes.remove(scope);
+        }
+    }
+
+    pub fn clear(&self) {
+        if let Ok(mut scoped) = self.contents.lock() {
+            *scoped = ScopedContents::default();
+        }
+    }
+}
+
+impl Default for WorkingMemory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+const SUMMARY_HYPOTHESIS_CHARS: usize = 80;
+
+fn summarize(state: &CognitiveState) -> String {
+    let mut hypothesis: String = state.current_hypothesis.chars().take(SUMMARY_HYPOTHESIS_CHARS).collect();
+    if state.current_hypothesis.chars().count() > SUMMARY_HYPOTHESIS_CHARS {
+        hypothesis.push_str("...");
+    }
+    format!("{}: {} (confidence {:.2})", state.stimulus_id, hypothesis, state.confidence_level)
+}
+
+/// Capitalized words that do not start a sentence, e.g. "Paris" in "Is Paris far?".
+fn extract_entities(text: &str) -> Vec<String> {
+    let mut entities = Vec::new();
+    let mut sentence_start = true;
+    for raw in text.split_whitespace() {
+        let word = raw.trim_matches(|c: char| !c.is_alphanumeric());
+        let starts_upper = word.chars().next().is_some_and(char::is_uppercase);
+        if starts_upper && !sentence_start && word.chars().count() > 1 && !entities.iter().any(|entity| entity == word) {
+            entities.push(word.to_string());
+        }
+        sentence_start = raw.ends_with(['.', '!', '?']);
+    }
+    entities
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    use crate::recursive_cognition_engine::{
+        BasicRecursiveCognitionEngine, EthicalAssessmentReport, RecursiveCognitionEngine, Stimulus, StimulusContent,
+    };
+
+    fn state(stimulus_id: &str, text: &str, confidence_level: f64) -> CognitiveState {
+        CognitiveState {
+            state_id: format!("state_{}", stimulus_id),
+            stimulus_id: stimulus_id.to_string(),
+            stimulus_text: text.to_string(),
+            current_hypothesis: format!("Hypothesis for {}", stimulus_id),
+            confidence_level,
+            ..Default::default()
+        }
+    }
+
+    fn assessed(mut state: CognitiveState, alignment_status: AlignmentStatus, concerns: &[&str]) -> CognitiveState {
+        state.ethical_assessment = Some(EthicalAssessmentReport {
+            alignment_status,
+            ethical_concerns: concerns.iter().map(|concern| concern.to_string()).collect(),
+            ..Default::default()
+        });
+        state
+    }
+
+    #[test]
+    fn test_eviction_summarizes_and_bounds_context() {
+        let config = WorkingMemoryConfig { max_states: 2, max_summary_lines: 1, eviction: EvictionPolicy::LowestConfidence, ..Default::default() };
+        let memory = WorkingMemory::new().with_config(config);
+        memory.remember(&state("s1", "", 0.9)).unwrap();
+        memory.remember(&state("s2", "", 0.3)).unwrap();
+        memory.remember(&state("s3", "", 0.8)).unwrap();
+        let context = memory.context("");
+        let kept: Vec<&str> = context.prior_states.iter().map(|prior| prior.stimulus_id.as_str()).collect();
+        assert_eq!(kept, vec!["s1", "s3"]);
+        assert_eq!(context.summary, vec!["s2: Hypothesis for s2 (confidence 0.30)".to_string()]);
+
+        memory.remember(&state("s4", "", 0.7)).unwrap();
+        let context = memory.context("");
+        assert_eq!(context.prior_states.len(), 2);
+        assert_eq!(context.summary[0], "1 earlier cycle(s) omitted");
+        assert!(context.summary[1].starts_with("s4: "));
+    }
+
+    #[test]
+    fn test_entities_and_concerns_carry_over_until_resolved() {
+        let config = WorkingMemoryConfig { max_entities: 2, ..Default::default() };
+        let memory = WorkingMemory::new().with_config(config);
+        memory.remember(&assessed(state("s1", "Tell me about Ada and Babbage.", 0.5), AlignmentStatus::RequiresReview, &["Privacy"])).unwrap();
+        memory.remember(&state("s2", "What did Ada build?", 0.5)).unwrap();
+        memory.remember(&state("s3", "When did Turing meet Ada?", 0.5)).unwrap();
+
+        let context = memory.context("");
+        assert_eq!(context.entities, vec!["Ada".to_string(), "Turing".to_string()]);
+        assert_eq!(context.unresolved_concerns, vec!["Privacy".to_string()]);
+
+        memory.remember(&assessed(state("s2", "", 0.5), AlignmentStatus::Aligned, &[])).unwrap();
+        assert_eq!(memory.context("").unresolved_concerns, vec!["Privacy".to_string()]);
+        memory.remember(&assessed(state("s1", "", 0.5), AlignmentStatus::Aligned, &[])).unwrap();
+        assert!(memory.context("").unresolved_concerns.is_empty());
+
+        memory.remember(&assessed(state("s5", "", 0.5), AlignmentStatus::Misaligned, &["Harm"])).unwrap();
+        assert!(memory.resolve_concern("Harm"));
+        assert!(!memory.resolve_concern("Harm"));
+        memory.clear();
+        assert!(memory.context("").is_empty());
+    }
+
+    #[test]
+    fn test_engine_loads_context_from_prior_cycles() {
+        let memory = Arc::new(WorkingMemory::new());
+        let engine = BasicRecursiveCognitionEngine::new().with_working_memory(memory.clone());
+        let stimulus = |id: &str, text: &str| Stimulus {
+            id: id.to_string(),
+            content: StimulusContent::Text(text.to_string()),
+            metadata: HashMap::new(),
+        };
+
+        let first = engine.execute_full_cycle(stimulus("q1", "Who was Ada Lovelace?")).unwrap();
+        assert_eq!(first.working_context, Some(WorkingContext::default()));
+        let second = engine.execute_full_cycle(stimulus("q2", "What did she write?")).unwrap();
+        let context = second.working_context.unwrap();
+        assert_eq!(context.prior_states.len(), 1);
+        assert_eq!(context.prior_states[0].state_id, first.state_id);
+        assert_eq!(context.entities, vec!["Ada".to_string(), "Lovelace".to_string()]);
+        assert!(second.history_log.iter().any(|entry| entry == "Working memory loaded: 1 prior state(s), 0 unresolved concern(s)"));
+        assert_eq!(memory.context("").prior_states.len(), 2);
+    }
+
+    #[test]
+    fn test_scopes_are_isolated_and_remembering_is_idempotent() {
+        let memory = Arc::new(WorkingMemory::new().with_config(WorkingMemoryConfig { max_scopes: 2, ..Default::default() }));
+        let engine = BasicRecursiveCognitionEngine::new().with_working_memory(memory.clone());
+        let stimulus = |id: &str, session: &str, text: &str| Stimulus {
+            id: id.to_string(),
+            content: StimulusContent::Text(text.to_string()),
+            metadata: HashMap::from([(SCOPE_KEY.to_string(), session.to_string())]),
+        };
+
+        let first = engine.execute_full_cycle(stimulus("q1", "session_a", "Who was Ada Lovelace?")).unwrap();
+        let other = engine.execute_full_cycle(stimulus("q2", "session_b", "What is a treaty?")).unwrap();
+        assert_eq!(other.working_context, Some(WorkingContext::default())); // Nothing from session_a
+        let second = engine.execute_full_cycle(stimulus("q3", "session_a", "What did she write?")).unwrap();
+        assert_eq!(second.working_context.as_ref().unwrap().prior_states[0].state_id, first.state_id);
+
+        // A finalization that runs again (retry, resume, replay) replaces the held state.
+        memory.remember(&second).unwrap();
+        let context = memory.context("session_a");
+        assert_eq!(context.prior_states.len(), 2);
+        assert!(memory.context("").is_empty());
+
+        engine.execute_full_cycle(stimulus("q4", "session_c", "Hello")).unwrap();
+        assert!(memory.context("session_b").is_empty()); // Least recently used scope was forgotten
+        memory.forget("session_a");
+        assert!(memory.context("session_a").is_empty());
+    }
+}
@@ -1,4 +1,194 @@
 // or4cl3_core/src/scalability/mod.rs
+
+//! Scalability
+//! Components for running OR4CL3 workloads at volume. `BatchProcessor` runs
+//! many cognition cycles concurrently on a bounded worker pool.
+
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+use crate::recursive_cognition_engine::{CognitionError, CognitionErrorKind, CognitiveState, RecursiveCognitionEngine, Stimulus};
+
 pub fn placeholder_function() -> String {
     "Hello from Scalability".to_string()
 }
+
+/// Result of one stimulus in a batch. `index` is the stimulus' position in the input.
+#[derive(Debug, Clone)]
+pub struct BatchItemResult {
+    pub index: usize,
+    pub stimulus_id: String,
+    pub result: Result<CognitiveState, CognitionError>,
+}
+
+#[derive(Debug, Clone)]
+pub struct BatchReport {
+    pub results: Vec<BatchItemResult>, // Same order as the input stimuli
+    pub succeeded: usize,
+    pub failed: usize,
+}
+
+impl BatchReport {
+    pub fn failures(&self) -> impl Iterator<Item = &BatchItemResult> {
+        self.results.iter().filter(|item| item.result.is_err())
+    }
+}
+
+/// A failure of the engine itself rather than of one of its stages; not worth retrying.
+fn engine_failure(message: String) -> CognitionError {
+    let kind = CognitionErrorKind::DependencyFailure { dependency: "engine".to_string(), message, retryable: false };
+    CognitionError::new(None, kind)
+}
+
+/// Runs `execute_full_cycle` for many stimuli on a pool of worker threads.
+/// A failing (or panicking) item is reported in its result slot and never fails the batch.
+pub struct BatchProcessor {
+    max_parallelism: usize,
+}
+
+impl BatchProcessor {
+    /// `max_parallelism` is the number of worker threads; 0 is treated as 1.
+    pub fn new(max_parallelism: usize) -> Self {
+        Self { max_parallelism: max_parallelism.max(1) }
+    }
+
+    /// Uses one worker per available CPU.
+    pub fn with_available_parallelism() -> Self {
+        Self::new(thread::available_parallelism().map(|n| n.get()).unwrap_or(1))
+    }
+
+    pub fn max_parallelism(&self) -> usize {
+        self.max_parallelism
+    }
+
+    pub fn process_batch<E>(&self, engine: &E, stimuli: Vec<Stimulus>) -> BatchReport
+    where
+        E: RecursiveCognitionEngine + Sync,
+    {
+        let total = stimuli.len();
+        let next_index = AtomicUsize::new(0);
+        let slots: Vec<Mutex<Option<BatchItemResult>>> = (0..total).map(|_| Mutex::new(None)).collect();
+        let workers = self.max_parallelism.min(total);
+
+        thread::scope(|scope| {
+            for _ in 0..workers {
+                scope.spawn(|| loop {
+                    let index = next_index.fetch_add(1, Ordering::SeqCst);
+                    let Some(stimulus) = stimuli.get(index) else {
+                        break;
+                    };
+                    let result = panic::catch_unwind(AssertUnwindSafe(|| engine.execute_full_cycle(stimulus.clone())))
+                        .unwrap_or_else(|_| Err(engine_failure(format!("Cycle panicked while processing {}", stimulus.id))));
+                    let item = BatchItemResult { index, stimulus_id: stimulus.id.clone(), result };
+                    if let Ok(mut slot) = slots[index].lock() {
+                        *slot = Some(item);
+                    }
+                });
+            }
+        });
+
+        let results: Vec<BatchItemResult> = slots
+            .into_iter()
+            .enumerate()
+            .map(|(index, slot)| {
+                slot.into_inner().ok().flatten().unwrap_or_else(|| BatchItemResult {
+                    index,
+                    stimulus_id: stimuli[index].id.clone(),
+                    result: Err(engine_failure("Stimulus was not processed".to_string())),
+                })
+            })
+            .collect();
+        let succeeded = results.iter().filter(|item| item.result.is_ok()).count();
+        BatchReport { failed: results.len() - succeeded, succeeded, results }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use crate::recursive_cognition_engine::test_engine::DelegatingTestEngine;
+    use crate::recursive_cognition_engine::{BasicRecursiveCognitionEngine, StimulusContent};
+
+    fn create_stimuli(count: usize) -> Vec<Stimulus> {
+        (0..count)
+            .map(|i| Stimulus {
+                id: format!("batch_stimulus_{}", i),
+                content: StimulusContent::Text(format!("Stimulus number {}", i)),
+                metadata: HashMap::new(),
+            })
+            .collect()
+    }
+
+    /// Engine that fails odd-numbered stimuli, with the peak number of cycles that ran at once.
+    fn tracking_engine() -> (DelegatingTestEngine<BasicRecursiveCognitionEngine>, Arc<AtomicUsize>) {
+        let running = AtomicUsize::new(0);
+        let peak = Arc::new(AtomicUsize::new(0));
+        let tracked_peak = peak.clone();
+        let engine = DelegatingTestEngine::new(BasicRecursiveCognitionEngine::new()).on_full_cycle(move |inner, stimulus| {
+            let now_running = running.fetch_add(1, Ordering::SeqCst) + 1;
+            tracked_peak.fetch_max(now_running, Ordering::SeqCst);
+            thread::sleep(Duration::from_millis(5));
+            if stimulus.id == "batch_stimulus_panic" {
+                panic!("simulated engine crash");
+            }
+            let result = if stimulus.id.ends_with(['1', '3', '5', '7', '9']) {
+                Err(CognitionError::invalid_input(None, format!("Rejected {}", stimulus.id)))
+            } else {
+                inner.execute_full_cycle(stimulus)
+            };
+            running.fetch_sub(1, Ordering::SeqCst);
+            result
+        });
+        (engine, peak)
+    }
+
+    #[test]
+    fn test_batch_preserves_order_and_reports_item_errors() {
+        let (engine, _) = tracking_engine();
+        let report = BatchProcessor::new(4).process_batch(&engine, create_stimuli(20));
+
+        assert_eq!(report.results.len(), 20);
+        assert_eq!(report.succeeded, 10);
+        assert_eq!(report.failed, 10);
+        for (i, item) in report.results.iter().enumerate() {
+            assert_eq!(item.index, i);
+            assert_eq!(item.stimulus_id, format!("batch_stimulus_{}", i));
+            assert_eq!(item.result.is_ok(), i % 2 == 0);
+        }
+        assert!(report.failures().all(|item| item.result.as_ref().unwrap_err().to_string().contains("Rejected")));
+    }
+
+    #[test]
+    fn test_batch_respects_parallelism_bound() {
+        let (engine, peak) = tracking_engine();
+        BatchProcessor::new(3).process_batch(&engine, create_stimuli(12));
+        let peak = peak.load(Ordering::SeqCst);
+        assert!(peak > 1, "cycles never overlapped");
+        assert!(peak <= 3);
+        assert_eq!(BatchProcessor::new(0).max_parallelism(), 1);
+    }
+
+    #[test]
+    fn test_panicking_item_does_not_fail_batch() {
+        let (engine, _) = tracking_engine();
+        let mut stimuli = create_stimuli(2);
+        stimuli[1].id = "batch_stimulus_panic".to_string();
+        let report = BatchProcessor::new(2).process_batch(&engine, stimuli);
+        assert_eq!(report.succeeded, 1);
+        assert!(report.results[1].result.as_ref().unwrap_err().to_string().contains("panicked"));
+    }
+
+    #[test]
+    fn test_empty_batch() {
+        let report = BatchProcessor::new(2).process_batch(&BasicRecursiveCognitionEngine::new(), vec![]);
+        assert!(report.results.is_empty());
+        assert_eq!(report.succeeded, 0);
+    }
+}
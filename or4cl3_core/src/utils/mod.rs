@@ -1,4 +1,54 @@
 // or4cl3_core/src/utils/mod.rs
+use std::collections::HashSet;
+
 pub fn placeholder_function() -> String {
     "Hello from Utils".to_string()
 }
+
+/// Splits text into a set of lowercase alphanumeric tokens.
+/// Tokens shorter than two characters are dropped as noise.
+pub fn tokenize(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|token| token.chars().count() > 1)
+        .map(|token| token.to_lowercase())
+        .collect()
+}
+
+/// Jaccard similarity between the token sets of two texts, in [0.0, 1.0].
+/// Two texts without any tokens are considered dissimilar.
+pub fn jaccard_similarity(a: &str, b: &str) -> f32 {
+    token_set_similarity(&tokenize(a), &tokenize(b))
+}
+
+/// Jaccard similarity between two already tokenized sets, in [0.0, 1.0].
+pub fn token_set_similarity(tokens_a: &HashSet<String>, tokens_b: &HashSet<String>) -> f32 {
+    let union = tokens_a.union(tokens_b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    tokens_a.intersection(tokens_b).count() as f32 / union as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize_normalizes_case_and_punctuation() {
+        let tokens = tokenize("Redlining, REDLINING and a policy!");
+        assert!(tokens.contains("redlining"));
+        assert!(tokens.contains("policy"));
+        assert!(!tokens.contains("a")); // Too short
+        assert_eq!(tokens.len(), 3);
+        assert_eq!(tokenize("é où"), HashSet::from(["où".to_string()])); // Counts chars, not bytes
+    }
+
+    #[test]
+    fn test_jaccard_similarity_bounds() {
+        assert_eq!(jaccard_similarity("urban surveillance", "urban surveillance"), 1.0);
+        assert_eq!(jaccard_similarity("urban surveillance", "medical ethics"), 0.0);
+        assert_eq!(jaccard_similarity("", ""), 0.0);
+        let partial = jaccard_similarity("urban surveillance history", "urban surveillance today");
+        assert!(partial > 0.0 && partial < 1.0);
+    }
+}